@@ -0,0 +1,101 @@
+use std::fmt::{self, Display};
+use std::ops::Range;
+
+/// An input on which a solver and its reference disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch<I, O> {
+    /// The seed whose generated input first disagreed, before shrinking.
+    pub seed: u64,
+    pub input: I,
+    /// What the reference returned.
+    pub expected: O,
+    pub got: O,
+}
+
+impl<O: Display> Display for Mismatch<String, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "seed {} disagrees, shrunk to:", self.seed)?;
+        writeln!(f, "{}", self.input)?;
+        write!(f, "expected {}, got {}", self.expected, self.got)
+    }
+}
+
+impl<I, O> Mismatch<I, O> {
+    pub fn map_input<J>(self, f: impl FnOnce(&I) -> J) -> Mismatch<J, O> {
+        Mismatch {
+            seed: self.seed,
+            input: f(&self.input),
+            expected: self.expected,
+            got: self.got,
+        }
+    }
+}
+
+/// Runs `solve` and `reference` on the input generated for every seed, and returns the first
+/// disagreement after shrinking it.
+///
+/// Shrinking repeatedly replaces the input with the first of `shrink`'s smaller candidates
+/// that still disagrees, until none does.
+pub fn differential<I, O: PartialEq>(
+    seeds: Range<u64>,
+    generate: impl Fn(u64) -> I,
+    shrink: impl Fn(&I) -> Vec<I>,
+    solve: impl Fn(&I) -> O,
+    reference: impl Fn(&I) -> O,
+) -> Option<Mismatch<I, O>> {
+    let disagreement = |input: &I| {
+        let (expected, got) = (reference(input), solve(input));
+        (expected != got).then_some((expected, got))
+    };
+    let (seed, mut input, (mut expected, mut got)) = seeds.into_iter().find_map(|seed| {
+        let input = generate(seed);
+        disagreement(&input).map(|d| (seed, input, d))
+    })?;
+    while let Some((smaller, d)) = shrink(&input)
+        .into_iter()
+        .find_map(|c| disagreement(&c).map(|d| (c, d)))
+    {
+        (input, (expected, got)) = (smaller, d);
+    }
+    Some(Mismatch {
+        seed,
+        input,
+        expected,
+        got,
+    })
+}
+
+/// Every copy of `items` with one element removed, keeping at least `min_len` elements.
+pub fn without_one<T: Clone>(items: &[T], min_len: usize) -> Vec<Vec<T>> {
+    if items.len() <= min_len {
+        return Vec::new();
+    }
+    (0..items.len())
+        .map(|i| [&items[..i], &items[i + 1..]].concat())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_differential() {
+        let sum = |v: &Vec<u32>| v.iter().sum::<u32>();
+        // Wrong whenever the input contains a 7.
+        let buggy = |v: &Vec<u32>| v.iter().map(|&x| if x == 7 { 0 } else { x }).sum();
+        let generate = |seed: u64| (0..10).map(|i| (seed as u32 + i) % 10).collect();
+        let shrink = |v: &Vec<u32>| without_one(v, 1);
+
+        assert_eq!(differential(0..100, generate, shrink, sum, sum), None);
+        let m = differential(0..100, generate, shrink, buggy, sum).unwrap();
+        assert_eq!(m.seed, 0);
+        assert_eq!((m.input, m.expected, m.got), (vec![7], 7, 0));
+    }
+
+    #[test]
+    fn test_without_one() {
+        assert_eq!(without_one(&[1, 2, 3], 2), [[2, 3], [1, 3], [1, 2]]);
+        assert!(without_one(&[1, 2], 2).is_empty());
+    }
+}
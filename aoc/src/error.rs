@@ -0,0 +1,137 @@
+use std::fmt;
+use winnow::error::{ContextError, StrContext};
+
+/// A parse failure located in the original input, with a human readable label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Byte offset into the input.
+    pub offset: usize,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending line, without its line ending.
+    pub snippet: String,
+    pub label: String,
+}
+
+impl ParseError {
+    pub fn new(input: &str, offset: usize, label: impl Into<String>) -> Self {
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        Self {
+            offset,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            label: label.into(),
+        }
+    }
+
+    /// Describes whatever sits at `offset`, for labels like "expected `-`, found `x`".
+    pub fn found(input: &str, offset: usize) -> String {
+        match input[offset..].chars().next() {
+            None => "end of input".to_string(),
+            Some('\n') => "newline".to_string(),
+            Some('\r') => "carriage return".to_string(),
+            Some(c) => format!("`{}`", c.escape_debug()),
+        }
+    }
+}
+
+impl From<winnow::error::ParseError<&str, ContextError>> for ParseError {
+    fn from(e: winnow::error::ParseError<&str, ContextError>) -> Self {
+        let (input, offset) = (*e.input(), e.offset());
+        let found = Self::found(input, offset);
+
+        let mut labels = Vec::new();
+        let mut expected = Vec::new();
+        for c in e.inner().context() {
+            match c {
+                StrContext::Label(l) => labels.push(*l),
+                StrContext::Expected(v) => expected.push(v.to_string()),
+                _ => {}
+            }
+        }
+        let mut label = match expected.as_slice() {
+            [] => format!("unexpected {found}"),
+            [e] => format!("expected {e}, found {found}"),
+            [init @ .., last] => format!("expected {} or {last}, found {found}", init.join(", ")),
+        };
+        if let Some(l) = labels.last() {
+            label = format!("invalid {l}: {label}");
+        }
+        Self::new(input, offset, label)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let caret = " ".repeat(self.column - 1);
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.label
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{gutter} | {caret}^")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winnow::Parser;
+    use winnow::ascii::dec_uint;
+    use winnow::combinator::{separated, separated_pair};
+    use winnow::error::StrContextValue;
+
+    fn parse(input: &str) -> Result<Vec<(u32, u32)>, ParseError> {
+        let range = separated_pair(
+            dec_uint::<_, u32, ContextError>,
+            '-'.context(StrContext::Expected('-'.into())),
+            dec_uint,
+        );
+        let mut parser = separated(1.., range, '\n')
+            .context(StrContext::Label("ranges"))
+            .context(StrContext::Expected(StrContextValue::Description(
+                "a range",
+            )));
+        Ok(parser.parse(input)?)
+    }
+
+    #[test]
+    fn test_location() {
+        let e = ParseError::new("ab\r\ncdé\nf", 6, "label");
+        assert_eq!((e.line, e.column, e.snippet.as_str()), (2, 3, "cdé"));
+        let e = ParseError::new("ab\ncd", 5, "label");
+        assert_eq!((e.line, e.column, e.snippet.as_str()), (2, 3, "cd"));
+    }
+
+    #[test]
+    fn test_display() {
+        let e = ParseError::new("1-2\n3x4", 5, "expected `-`, found `x`");
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 2: expected `-`, found `x`\n  |\n2 | 3x4\n  |  ^"
+        );
+    }
+
+    #[test]
+    fn test_from_winnow() {
+        assert_eq!(parse("1-2").unwrap(), [(1, 2)]);
+        let e = parse("x").unwrap_err();
+        assert_eq!(e.label, "invalid ranges: expected a range, found `x`");
+        let e = parse("1-2\n3").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 4));
+        assert_eq!(e.label, "unexpected newline");
+    }
+}
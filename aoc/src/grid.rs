@@ -0,0 +1,274 @@
+use anyhow::Result;
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+/// `(x, y)`, with `y` counting rows from the top.
+pub type Pos = (usize, usize);
+
+const STEPS4: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const STEPS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid stored row-major in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map, one row per line (`\n` or `\r\n`). Characters for which `tile`
+    /// returns `None`, and rows of the wrong width, are reported with their line and column.
+    pub fn parse(input: &str, tile: impl Fn(char) -> Option<T>) -> Result<Self> {
+        let (mut width, mut height, mut cells) = (0, 0, Vec::new());
+        let mut offset = 0;
+        for line in input.split_inclusive('\n') {
+            let row = line.strip_suffix('\n').unwrap_or(line);
+            let row = row.strip_suffix('\r').unwrap_or(row);
+            for (i, c) in row.char_indices() {
+                let t = tile(c).ok_or_else(|| {
+                    let found = ParseError::found(input, offset + i);
+                    ParseError::new(input, offset + i, format!("unexpected {found}"))
+                })?;
+                cells.push(t);
+            }
+            if height == 0 {
+                width = cells.len();
+            } else if cells.len() != (height + 1) * width {
+                let found = cells.len() - height * width;
+                let label = format!("expected a row of {width} tiles, found {found}");
+                return Err(ParseError::new(input, offset, label).into());
+            }
+            height += 1;
+            offset += line.len();
+        }
+        if cells.is_empty() {
+            return Err(ParseError::new(input, 0, "expected a non-empty grid").into());
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator {
+        self.cells.chunks_exact_mut(self.width.max(1))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// The in-bounds orthogonal neighbours of `pos`. Doesn't borrow the grid, so it can be
+    /// used while updating it.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + use<T> {
+        self.neighbors(pos, &STEPS4)
+    }
+
+    /// The in-bounds orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + use<T> {
+        self.neighbors(pos, &STEPS8)
+    }
+
+    fn neighbors(
+        &self,
+        (x, y): Pos,
+        steps: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + use<T> {
+        let (width, height) = (self.width, self.height);
+        steps
+            .iter()
+            .filter_map(move |&(dx, dy)| x.checked_add_signed(dx).zip(y.checked_add_signed(dy)))
+            .filter(move |&(x, y)| x < width && y < height)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside a {width}x{height} grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for t in row {
+                write!(f, "{t}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Bit(bool);
+
+    impl Display for Bit {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", if self.0 { '#' } else { '.' })
+        }
+    }
+
+    fn bit(c: char) -> Option<Bit> {
+        match c {
+            '#' => Some(Bit(true)),
+            '.' => Some(Bit(false)),
+            _ => None,
+        }
+    }
+
+    fn parse_error(input: &str) -> ParseError {
+        let e = Grid::parse(input, bit).unwrap_err();
+        e.downcast_ref::<ParseError>().unwrap().clone()
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let grid = Grid::parse("#..\n.#.", bit)?;
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 1)], Bit(true));
+        assert_eq!(grid.row(0), [Bit(true), Bit(false), Bit(false)]);
+        assert_eq!(grid.to_string(), "#..\n.#.");
+        assert_eq!(Grid::parse("#..\r\n.#.\r\n", bit)?, grid);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = parse_error("#.\n.#x");
+        assert_eq!(
+            (e.line, e.column, e.label.as_str()),
+            (2, 3, "unexpected `x`")
+        );
+        let e = parse_error("#. \n.#");
+        assert_eq!(
+            (e.line, e.column, e.label.as_str()),
+            (1, 3, "unexpected ` `")
+        );
+        let e = parse_error("#.\r.#");
+        let label = "unexpected carriage return";
+        assert_eq!((e.line, e.column, e.label.as_str()), (1, 3, label));
+        let e = parse_error("#.\n.");
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.label, "expected a row of 2 tiles, found 1");
+        assert_eq!(parse_error("").label, "expected a non-empty grid");
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = Grid::new(3, 2, 0);
+        grid[(2, 1)] = 5;
+        *grid.get_mut((0, 1)).unwrap() = 1;
+        assert_eq!(grid.get((2, 1)), Some(&5));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.get_mut((3, 1)), None);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[0, 0, 0], [1, 0, 5]]);
+        assert_eq!(
+            grid.map(|&v| v > 0).to_string(),
+            "falsefalsefalse\ntruefalsetrue"
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, ());
+        let sorted = |it: &mut dyn Iterator<Item = Pos>| {
+            let mut v: Vec<_> = it.collect();
+            v.sort_unstable();
+            v
+        };
+        assert_eq!(sorted(&mut grid.neighbors4((0, 0))), [(0, 1), (1, 0)]);
+        assert_eq!(
+            sorted(&mut grid.neighbors8((0, 0))),
+            [(0, 1), (1, 0), (1, 1)]
+        );
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+        let positions: Vec<_> = grid.positions().take(4).collect();
+        assert_eq!(positions, [(0, 0), (1, 0), (2, 0), (0, 1)]);
+    }
+}
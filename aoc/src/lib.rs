@@ -0,0 +1,90 @@
+use anyhow::Result;
+use std::fmt::Display;
+use std::fs::read_to_string;
+use std::time::{Duration, Instant};
+use winnow::Parser;
+use winnow::error::{ContextError, ParserError};
+
+pub mod check;
+mod error;
+pub mod grid;
+pub mod ranges;
+pub use error::ParseError;
+pub use grid::Grid;
+pub use ranges::merge_ranges;
+
+/// A single day of the calendar: how to parse its input and how to solve each part.
+pub trait Solution {
+    const DAY: u8;
+    /// Day 12 only has one puzzle; the runner skips `part2` when this is `false`.
+    const HAS_PART2: bool = true;
+
+    /// The parsed input. It may borrow from the raw text (see day 11).
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display>;
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display>;
+}
+
+/// Runs `parser` over the whole of `input`, reporting failures as a [`ParseError`].
+pub fn parse<'i, O, E>(mut parser: impl Parser<&'i str, O, E>, input: &'i str) -> Result<O>
+where
+    E: ParserError<&'i str, Inner = ContextError>,
+{
+    Ok(parser.parse(input).map_err(ParseError::from)?)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: String,
+    pub time: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayResult {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+/// Parses `input` and solves `part`, or every part the day has when `part` is `None`.
+/// Asking for a part the day doesn't have yields no results for it rather than an error.
+pub fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<DayResult> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let mut parts = Vec::new();
+    for p in [1, 2] {
+        if part.is_some_and(|part| part != p) || (p == 2 && !S::HAS_PART2) {
+            continue;
+        }
+        let start = Instant::now();
+        let answer = match p {
+            1 => S::part1(&input)?.to_string(),
+            _ => S::part2(&input)?.to_string(),
+        };
+        let time = start.elapsed();
+        parts.push(PartResult {
+            part: p,
+            answer,
+            time,
+        });
+    }
+    Ok(DayResult {
+        day: S::DAY,
+        parse_time,
+        parts,
+    })
+}
+
+/// Solves every part for `input.txt` in the current directory and prints the answers.
+pub fn run<S: Solution>() -> Result<()> {
+    let result = solve::<S>(&read_to_string("input.txt")?, None)?;
+    for PartResult { part, answer, .. } in result.parts {
+        println!("Task {part}: {answer}");
+    }
+    Ok(())
+}
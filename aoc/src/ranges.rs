@@ -0,0 +1,45 @@
+/// Integers whose inclusive ranges [`merge_ranges`] can join.
+pub trait Id: Ord + Copy {
+    /// The next integer, or `self` if there is none.
+    fn saturating_next(self) -> Self;
+}
+
+macro_rules! impl_id {
+    ($($t:ty),*) => {
+        $(impl Id for $t {
+            fn saturating_next(self) -> Self {
+                self.saturating_add(1)
+            }
+        })*
+    };
+}
+
+impl_id!(u8, u16, u32, u64, u128, usize);
+
+/// Sorts inclusive ranges and merges the overlapping and adjacent ones, so the result is
+/// disjoint and in increasing order. Empty ranges, with `min > max`, are dropped.
+pub fn merge_ranges<T: Id>(mut ranges: Vec<(T, T)>) -> Vec<(T, T)> {
+    ranges.retain(|(min, max)| min <= max);
+    ranges.sort_unstable();
+    let mut merged: Vec<(T, T)> = Vec::with_capacity(ranges.len());
+
+    for (min, max) in ranges {
+        match merged.last_mut() {
+            Some((_, end)) if min <= end.saturating_next() => *end = max.max(*end),
+            _ => merged.push((min, max)),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_ranges() {
+        let ranges: Vec<(u64, u64)> = vec![(20, 10), (11, 15), (1, 3), (4, 4), (u64::MAX, 0)];
+        assert_eq!(merge_ranges(ranges), [(1, 4), (11, 15)]);
+        assert_eq!(merge_ranges(vec![(0u8, 255), (255, 255)]), [(0, 255)]);
+    }
+}
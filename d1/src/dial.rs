@@ -0,0 +1,208 @@
+use anyhow::{Result, ensure};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{Direction, Rot};
+
+/// What one rotation did to the dial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub rot: Rot,
+    pub start: u32,
+    pub end: u32,
+    /// Clicks that pointed the dial at a target, including the last one.
+    pub passes: u64,
+    /// Whether the rotation ended on a target.
+    pub landed: bool,
+}
+
+/// A dial with `positions` clicks, numbered from 0, that starts at `start` and counts how
+/// often it stops at or passes each of its targets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    positions: u32,
+    start: u32,
+    targets: BTreeSet<u32>,
+}
+
+/// The puzzle's dial: 100 positions, starting at 50, watching 0.
+impl Default for Dial {
+    fn default() -> Self {
+        Self {
+            positions: 100,
+            start: 50,
+            targets: BTreeSet::from([0]),
+        }
+    }
+}
+
+impl Dial {
+    pub fn new(positions: u32, start: u32, targets: impl IntoIterator<Item = u32>) -> Result<Self> {
+        let targets: BTreeSet<_> = targets.into_iter().collect();
+        ensure!(positions > 0, "a dial needs at least one position");
+        for p in targets.iter().chain([&start]) {
+            ensure!(
+                *p < positions,
+                "position {p} is not on a {positions}-position dial"
+            );
+        }
+        Ok(Self {
+            positions,
+            start,
+            targets,
+        })
+    }
+
+    pub fn positions(&self) -> u32 {
+        self.positions
+    }
+
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    pub fn targets(&self) -> &BTreeSet<u32> {
+        &self.targets
+    }
+
+    /// Where the dial points after turning `rot` from `pos`.
+    pub fn turn(&self, pos: u32, Rot(direction, distance): Rot) -> u32 {
+        let (n, pos, distance) = (self.positions as u64, pos as u64, distance as u64);
+        let pos = match direction {
+            Direction::L => (pos + n - distance % n) % n,
+            Direction::R => (pos + distance) % n,
+        };
+        pos as u32
+    }
+
+    /// How many clicks of `rot`, turning from `pos`, point the dial at `target`.
+    pub fn passes(&self, pos: u32, Rot(direction, distance): Rot, target: u32) -> u64 {
+        let (n, pos, target) = (self.positions as u64, pos as u64, target as u64);
+        // The first click, from 1 to n, that reaches the target; then every n-th one does.
+        let first = match direction {
+            Direction::L => (pos + n - target - 1) % n + 1,
+            Direction::R => (target + n - pos - 1) % n + 1,
+        };
+        (distance as u64 + n - first) / n
+    }
+
+    /// Each rotation's effect, in order. With the default dial, `passes` and `landed` are
+    /// about position 0.
+    pub fn trace<'a>(&'a self, input: &'a [Rot]) -> impl Iterator<Item = Step> + 'a {
+        input.iter().scan(self.start, |pos, &rot| {
+            let start = *pos;
+            *pos = self.turn(start, rot);
+            Some(Step {
+                rot,
+                start,
+                end: *pos,
+                passes: self
+                    .targets
+                    .iter()
+                    .map(|&t| self.passes(start, rot, t))
+                    .sum(),
+                landed: self.targets.contains(pos),
+            })
+        })
+    }
+
+    /// How many rotations end on each target.
+    pub fn landings(&self, input: &[Rot]) -> BTreeMap<u32, u64> {
+        let mut counts: BTreeMap<_, _> = self.targets.iter().map(|&t| (t, 0)).collect();
+        let mut pos = self.start;
+        for &rot in input {
+            pos = self.turn(pos, rot);
+            if let Some(count) = counts.get_mut(&pos) {
+                *count += 1;
+            }
+        }
+        counts
+    }
+
+    /// How many clicks, over all rotations, point the dial at each target.
+    pub fn crossings(&self, input: &[Rot]) -> BTreeMap<u32, u64> {
+        let mut counts: BTreeMap<_, _> = self.targets.iter().map(|&t| (t, 0)).collect();
+        let mut pos = self.start;
+        for &rot in input {
+            for (&target, count) in &mut counts {
+                *count += self.passes(pos, rot, target);
+            }
+            pos = self.turn(pos, rot);
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Dial::new(100, 50, [0]).unwrap(), Dial::default());
+        let e = Dial::new(60, 60, [0]).unwrap_err();
+        assert_eq!(e.to_string(), "position 60 is not on a 60-position dial");
+        assert!(Dial::new(360, 0, [359, 360]).is_err());
+        assert!(Dial::new(0, 0, []).is_err());
+    }
+
+    #[test]
+    fn test_passes() -> Result<()> {
+        let dial = Dial::new(60, 0, [])?;
+        assert_eq!(dial.passes(0, Rot(Direction::R, 59), 0), 0);
+        assert_eq!(dial.passes(0, Rot(Direction::R, 60), 0), 1);
+        assert_eq!(dial.passes(0, Rot(Direction::L, 61), 59), 2);
+        assert_eq!(dial.passes(10, Rot(Direction::L, 10), 0), 1);
+        assert_eq!(dial.passes(10, Rot(Direction::R, 0), 10), 0);
+        assert_eq!(dial.turn(10, Rot(Direction::L, 135)), 55);
+        assert_eq!(dial.turn(10, Rot(Direction::R, u32::MAX)), 25);
+        Ok(())
+    }
+
+    #[test]
+    fn test_targets() -> Result<()> {
+        let dial = Dial::new(360, 0, [0, 90, 180, 270])?;
+        let input = [
+            Rot(Direction::R, 90),
+            Rot(Direction::R, 360),
+            Rot(Direction::L, 180),
+        ];
+        let landings = dial.landings(&input);
+        assert_eq!(
+            landings,
+            BTreeMap::from([(0, 0), (90, 2), (180, 0), (270, 1)])
+        );
+        let crossings = dial.crossings(&input);
+        assert_eq!(
+            crossings,
+            BTreeMap::from([(0, 2), (90, 2), (180, 1), (270, 2)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_trace() -> Result<()> {
+        let input = [
+            Rot(Direction::L, 68),
+            Rot(Direction::L, 30),
+            Rot(Direction::R, 48),
+        ];
+        let steps: Vec<_> = Dial::default()
+            .trace(&input)
+            .map(|s| (s.start, s.end, s.passes, s.landed))
+            .collect();
+        assert_eq!(
+            steps,
+            [(50, 82, 1, false), (82, 52, 0, false), (52, 0, 1, true)]
+        );
+
+        let dial = Dial::new(360, 0, [0, 90, 180, 270])?;
+        let input = [
+            Rot(Direction::R, 90),
+            Rot(Direction::R, 360),
+            Rot(Direction::L, 180),
+        ];
+        let passes: u64 = dial.trace(&input).map(|s| s.passes).sum();
+        assert_eq!(passes, dial.crossings(&input).values().sum());
+        Ok(())
+    }
+}
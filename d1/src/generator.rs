@@ -0,0 +1,42 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// `size` rotations of up to 999 clicks each, one per line.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    (0..size.max(1))
+        .map(|_| {
+            let direction = if rng.random_bool(0.5) { 'L' } else { 'R' };
+            format!("{direction}{}", rng.random_range(1..1000))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rot, parse, task1, task2};
+    use anyhow::Result;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = parse(&generate(1, 500))?;
+        assert_eq!(input.len(), 500);
+        assert!(input.iter().all(|Rot(_, n)| (1..1000).contains(n)));
+        // Every full turn of a rotation passes 0 once.
+        let turns: u64 = input.iter().map(|&Rot(_, n)| n as u64 / 100).sum();
+        assert!(task1(&input) <= task2(&input) && turns <= task2(&input));
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_small() -> Result<()> {
+        let input = generate(0, 3);
+        assert_eq!(input, "R730\nR699\nL196");
+        // 50 to 80 passing 0 7 times, to 79 passing it 7 times, to 83 passing it twice.
+        let input = parse(&input)?;
+        assert_eq!((task1(&input), task2(&input)), (0, 16));
+        Ok(())
+    }
+}
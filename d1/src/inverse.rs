@@ -0,0 +1,114 @@
+use crate::{Dial, Direction, Rot};
+
+/// The most clicks a single rotation can point the puzzle's dial at 0, as distances fit in a
+/// `u32`. Turning from anywhere but 0 reaches it; turning from 0 gets one fewer.
+const MOST_PASSES: u128 = (u32::MAX as u128).div_ceil(100);
+
+/// The shortest rotations, on the puzzle's dial, whose answers are `task1` and `task2`, or
+/// `None` if there are none or every such sequence is longer than `max_len` rotations.
+///
+/// Landing on 0 needs a click there, but once there, `R0`s land again without passing it, so
+/// up to `task2` rotations land by moving and the rest stay put. Moving rotations after a
+/// landing start from 0, which at best passes 0 `MOST_PASSES - 1` times, while the others
+/// reach `MOST_PASSES`. Passes can be traded one for one between moving rotations by changing
+/// their distances by 100, so every count between those bounds is reachable.
+pub fn synthesize(task1: u64, task2: u64, max_len: usize) -> Option<Vec<Rot>> {
+    // Wide enough that the length bound below can't overflow.
+    let (landings, passes) = (task1 as u128, task2 as u128);
+    if landings > 0 && passes == 0 {
+        return None;
+    }
+    let moving = landings.min(passes);
+    let stays = landings - moving;
+    let len = landings.max(stays + (passes + moving.saturating_sub(1)).div_ceil(MOST_PASSES));
+    if len > max_len as u128 {
+        return None;
+    }
+
+    // Rotations that don't land swing between 50 and 99, then the moving ones land on 0 and
+    // the rest stay there.
+    let dial = Dial::default();
+    let mut extra = passes - (len - stays);
+    let mut pos = dial.start();
+    let mut rots = Vec::with_capacity(len as usize);
+    for i in 0..len {
+        let most = if pos == 0 {
+            MOST_PASSES - 1
+        } else {
+            MOST_PASSES
+        };
+        let n = 1 + extra.min(most - 1);
+        let (direction, distance) = match (i >= len - landings, pos) {
+            _ if i >= len - stays => (Direction::R, 0),
+            (false, 50) => (Direction::L, 100 * n - 49),
+            (false, 99) => (Direction::R, 100 * n - 49),
+            (true, 0) => (Direction::R, 100 * n),
+            (true, p) => (Direction::R, 100 * n - p as u128),
+            _ => unreachable!("the dial is only ever at 0, 50 or 99"),
+        };
+        if distance > 0 {
+            extra -= n - 1;
+        }
+        let rot = Rot(direction, distance as u32);
+        pos = dial.turn(pos, rot);
+        rots.push(rot);
+    }
+    Some(rots)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{task1, task2};
+
+    fn answers(rots: &[Rot]) -> (u64, u64) {
+        (task1(rots), task2(rots))
+    }
+
+    #[test]
+    fn test_small() {
+        for landings in 0..12 {
+            for passes in 0..40 {
+                let rots = synthesize(landings, passes, 100);
+                if landings > 0 && passes == 0 {
+                    assert_eq!(rots, None);
+                    continue;
+                }
+                let rots = rots.unwrap();
+                assert_eq!(answers(&rots), (landings, passes));
+                assert_eq!(rots.len(), landings.max((passes > 0) as u64) as usize);
+                if let Some(shorter) = rots.len().checked_sub(1) {
+                    assert_eq!(synthesize(landings, passes, shorter), None);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_stays() -> anyhow::Result<()> {
+        assert_eq!(answers(&crate::parse("L50\nR0")?), (2, 1));
+        let rots = synthesize(2, 1, 10).unwrap();
+        assert_eq!((rots.len(), answers(&rots)), (2, (2, 1)));
+        let rots = synthesize(5, 3, 5).unwrap();
+        assert_eq!(rots.iter().filter(|r| r.1 == 0).count(), 2);
+        assert_eq!(answers(&rots), (5, 3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_large() {
+        assert_eq!(synthesize(0, u32::MAX as u64, 99), None);
+        let rots = synthesize(0, u32::MAX as u64, 100).unwrap();
+        assert_eq!((rots.len(), answers(&rots)), (100, (0, u32::MAX as u64)));
+        // The 99 rotations from 0 after a landing can't quite make up the difference.
+        assert_eq!(synthesize(100, u32::MAX as u64, 100), None);
+        let rots = synthesize(100, u32::MAX as u64, 1000).unwrap();
+        assert_eq!((rots.len(), answers(&rots)), (101, (100, u32::MAX as u64)));
+        let rots = synthesize(7, 5 * MOST_PASSES as u64, 10).unwrap();
+        assert_eq!(answers(&rots), (7, 5 * MOST_PASSES as u64));
+        // Past what a `u32` holds, then more rotations than allowed.
+        let rots = synthesize(3, 1 << 40, 100_000).unwrap();
+        assert_eq!((rots.len(), answers(&rots)), (25600, (3, 1 << 40)));
+        assert_eq!(synthesize(u64::MAX, u64::MAX, 100_000), None);
+    }
+}
@@ -0,0 +1,217 @@
+use anyhow::Result;
+use aoc::Solution;
+use std::fmt::{self, Display};
+use std::iter::repeat_n;
+use winnow::Parser;
+use winnow::ascii::{dec_uint, line_ending, space0, till_line_ending};
+use winnow::combinator::{
+    alt, cut_err, delimited, eof, opt, peek, preceded, separated, terminated,
+};
+use winnow::error::{StrContext, StrContextValue::Description};
+use winnow::token::{one_of, rest_len};
+
+mod dial;
+pub mod generator;
+mod inverse;
+pub mod reference;
+mod rotations;
+pub use dial::{Dial, Step};
+pub use inverse::synthesize;
+pub use rotations::Rotations;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    L,
+    R,
+}
+
+/// Turn the dial in a direction by a number of clicks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rot(pub Direction, pub u32);
+
+impl Display for Rot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}{}", self.0, self.1)
+    }
+}
+
+/// The most rotations an input may expand to, so repeat counts can't exhaust memory.
+pub const MAX_ROTATIONS: usize = 10_000_000;
+
+/// Each rotation with its repeat count, and the length of the input left at the count, or at
+/// the rotation if it has none, to locate errors.
+fn parse_(input: &mut &str) -> winnow::ModalResult<Vec<(Rot, usize, usize)>> {
+    let expected = |c: char| StrContext::Expected(c.into());
+    // `alt` rather than `dispatch!` on a token, so a bad direction is reported where it starts.
+    let direction = alt((
+        one_of(['L', '-']).value(Direction::L),
+        one_of(['R', '+']).value(Direction::R),
+    ))
+    .context(expected('L'))
+    .context(expected('R'))
+    .context(expected('+'))
+    .context(expected('-'));
+    let distance = dec_uint.context(StrContext::Expected(Description("a distance")));
+    let count = dec_uint.context(StrContext::Expected(Description("a repeat count")));
+    let times = opt(preceded('x', cut_err((rest_len, count))));
+    let rot =
+        (rest_len, direction, cut_err(distance), times).map(|(rest, d, n, times)| match times {
+            Some((rest, times)) => (Rot(d, n), times, rest),
+            None => (Rot(d, n), 1, rest),
+        });
+    let rots = separated(1.., cut_err(rot), (space0, ',', space0));
+
+    let comment = ('#', till_line_ending).void();
+    let line_end = peek(alt((line_ending.void(), '#'.void(), eof.void())));
+    let line = delimited(
+        space0,
+        alt((line_end.value(Vec::new()), rots)),
+        (space0, opt(comment)),
+    );
+    let end = eof
+        .context(expected(','))
+        .context(expected('#'))
+        .context(expected('\n'));
+    terminated(separated(0.., line, line_ending), cut_err(end))
+        .map(|lines: Vec<Vec<_>>| lines.concat())
+        .parse_next(input)
+}
+
+/// Parses rotations like `L68` or `-68`, and `R10x5` for five `R10`s. Several can share a
+/// line, separated by commas; blank lines and `#` comments are skipped. Fails if the repeats
+/// add up to more than [`MAX_ROTATIONS`].
+pub fn parse(input: &str) -> Result<Vec<Rot>> {
+    let rots = aoc::parse(parse_, input)?;
+    let mut total = 0usize;
+    for &(_, times, rest) in &rots {
+        total = total.saturating_add(times);
+        if total > MAX_ROTATIONS {
+            let label =
+                format!("repeat count too large, more than {MAX_ROTATIONS} rotations in all");
+            return Err(aoc::ParseError::new(input, input.len() - rest, label).into());
+        }
+    }
+    Ok(rots
+        .into_iter()
+        .flat_map(|(rot, times, _)| repeat_n(rot, times))
+        .collect())
+}
+
+/// How many rotations leave the dial, starting at 50, pointing at 0.
+pub fn task1(input: &[Rot]) -> u64 {
+    Dial::default().landings(input)[&0]
+}
+
+/// How many clicks, over all rotations, land the dial on 0.
+pub fn task2(input: &[Rot]) -> u64 {
+    Dial::default().crossings(input)[&0]
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input<'a> = Vec<Rot>;
+
+    fn parse(input: &str) -> Result<Vec<Rot>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r"L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+    #[test]
+    fn test_task1() -> Result<()> {
+        assert_eq!(task1(&parse(INPUT)?), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_task2() -> Result<()> {
+        assert_eq!(task2(&parse(INPUT)?), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_task2_1() -> Result<()> {
+        assert_eq!(task2(&parse("R1000")?), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_task2_2() -> Result<()> {
+        assert_eq!(task2(&parse("L1000")?), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_task2_wide() -> Result<()> {
+        let input = parse("R4294967295x101")?;
+        assert_eq!((task1(&input), task2(&input)), (5, 4337916968));
+        let input = parse(&format!("R4294967295x{}", MAX_ROTATIONS))?;
+        assert_eq!((task1(&input), task2(&input)), (500000, 429496729500000));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse("L68\nX30").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.label, "expected `L`, `R`, `+` or `-`, found `X`");
+        let e = parse("L68, R2y").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (1, 8));
+        assert_eq!(e.label, "expected `,`, `#` or newline, found `y`");
+        let e = parse("R10x").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!(e.label, "expected a repeat count, found end of input");
+
+        let e = parse("R1\nR1x18446744073709551615").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (2, 4));
+        assert!(e.label.starts_with("repeat count too large"));
+        let e = parse(&format!("R1x{}, L1", MAX_ROTATIONS)).unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (1, 14));
+        let e = parse("R1x99999999999999999999999").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (1, 4));
+    }
+
+    #[test]
+    fn test_extended_syntax() -> Result<()> {
+        let extended =
+            "# the example\n\nL68, -30 ,+48\r\nL5\nR60 # comment\n\nL55,L1\n-99\nR14\nL82\n";
+        assert_eq!(parse(extended)?, parse(INPUT)?);
+        assert_eq!(parse("R10x3, L1x0")?, parse("R10\nR10\nR10")?);
+        assert_eq!(parse("")?, []);
+        Ok(())
+    }
+
+    #[test]
+    fn test_main() -> Result<()> {
+        aoc::run::<Day1>()
+    }
+}
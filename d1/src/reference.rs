@@ -0,0 +1,126 @@
+use aoc::check::{Mismatch, differential, without_one};
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use crate::{Dial, Direction, Rot, generator, parse};
+
+type Solver = fn(&[Rot]) -> u64;
+
+/// Clicks `dial` one step at a time, returning how many rotations end on each target and how
+/// many clicks land on it, like [`Dial::landings`] and [`Dial::crossings`].
+pub fn simulate(dial: &Dial, input: &[Rot]) -> (BTreeMap<u32, u64>, BTreeMap<u32, u64>) {
+    let counts: BTreeMap<_, _> = dial.targets().iter().map(|&t| (t, 0)).collect();
+    let (mut landings, mut crossings) = (counts.clone(), counts);
+    let (n, mut pos) = (dial.positions(), dial.start());
+    for &Rot(direction, distance) in input {
+        for _ in 0..distance {
+            pos = match direction {
+                Direction::L => (pos + n - 1) % n,
+                Direction::R => (pos + 1) % n,
+            };
+            if let Some(count) = crossings.get_mut(&pos) {
+                *count += 1;
+            }
+        }
+        if let Some(count) = landings.get_mut(&pos) {
+            *count += 1;
+        }
+    }
+    (landings, crossings)
+}
+
+pub fn task1(input: &[Rot]) -> u64 {
+    simulate(&Dial::default(), input).0[&0]
+}
+
+pub fn task2(input: &[Rot]) -> u64 {
+    simulate(&Dial::default(), input).1[&0]
+}
+
+fn render(input: &[Rot]) -> String {
+    input
+        .iter()
+        .map(Rot::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Besides dropping rotations and shortening them, which moves every later one, tries
+/// replacing each prefix by the single rotation that leaves the dial in the same place, and
+/// dropping whole turns.
+fn shrink(input: &[Rot]) -> Vec<Vec<Rot>> {
+    let mut candidates = without_one(input, 1);
+    let mut pos = 50;
+    for (i, &Rot(direction, distance)) in input.iter().enumerate().take(input.len() - 1) {
+        pos = match direction {
+            Direction::L => (pos + 100 - distance % 100) % 100,
+            Direction::R => (pos + distance) % 100,
+        };
+        let rest = &input[i + 1..];
+        let prefix = match (pos + 50) % 100 {
+            0 => vec![],
+            d => vec![Rot(Direction::R, d)],
+        };
+        if i > 0 || prefix.is_empty() {
+            candidates.push([&prefix[..], rest].concat());
+        }
+    }
+    for (i, &Rot(direction, distance)) in input.iter().enumerate() {
+        for smaller in [distance / 2, distance - 1, distance.saturating_sub(100)] {
+            if smaller > 0 && smaller < distance {
+                let mut c = input.to_vec();
+                c[i] = Rot(direction, smaller);
+                candidates.push(c);
+            }
+        }
+    }
+    candidates
+}
+
+/// Compares both parts with the step-by-step simulation on inputs of up to 20 rotations.
+pub fn check(seeds: Range<u64>) -> Option<(u8, Mismatch<String, u64>)> {
+    let generate = |seed| parse(&generator::generate(seed, 1 + seed as usize % 20)).unwrap();
+    let parts: [(Solver, Solver); 2] = [(crate::task1, task1), (crate::task2, task2)];
+    (1..).zip(parts).find_map(|(part, (solve, reference))| {
+        let m = differential(
+            seeds.clone(),
+            generate,
+            |i: &Vec<Rot>| shrink(i),
+            |i| solve(i),
+            |i| reference(i),
+        )?;
+        Some((part, m.map_input(|i| render(i))))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference() {
+        if let Some((part, m)) = check(0..2000) {
+            panic!("part {part}: {m}");
+        }
+    }
+
+    #[test]
+    fn test_dial() -> anyhow::Result<()> {
+        let generate = |seed| parse(&generator::generate(seed, 1 + seed as usize % 20)).unwrap();
+        for dial in [
+            Dial::new(60, 0, [0, 13, 59])?,
+            Dial::new(360, 7, [0, 7, 90, 180, 270])?,
+            Dial::new(1, 0, [0])?,
+        ] {
+            let m = differential(
+                0..500,
+                generate,
+                |i: &Vec<Rot>| shrink(i),
+                |i| (dial.landings(i), dial.crossings(i)),
+                |i| simulate(&dial, i),
+            );
+            assert_eq!(m, None, "{dial:?}");
+        }
+        Ok(())
+    }
+}
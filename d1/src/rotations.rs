@@ -0,0 +1,423 @@
+use std::cmp::Ordering;
+use std::ops::{Bound, RangeBounds};
+
+use crate::{Dial, Rot};
+
+/// The effect of a run of rotations, for every position the dial could enter it at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Summary {
+    /// How far the run turns the dial to the right, modulo the number of positions.
+    offset: u32,
+    /// Indexed by entry position: clicks that point the dial at a target.
+    passes: Vec<u64>,
+    /// Indexed by entry position: rotations that end on a target.
+    landings: Vec<u64>,
+}
+
+impl Summary {
+    fn rot(dial: &Dial, rot: Rot) -> Self {
+        let n = dial.positions();
+        let passes = (0..n)
+            .map(|p| dial.targets().iter().map(|&t| dial.passes(p, rot, t)).sum())
+            .collect();
+        let landings = (0..n)
+            .map(|p| dial.targets().contains(&dial.turn(p, rot)) as u64)
+            .collect();
+        Self {
+            offset: dial.turn(0, rot),
+            passes,
+            landings,
+        }
+    }
+
+    /// `self` followed by `next`.
+    fn then(&self, next: &Self) -> Self {
+        let n = self.passes.len() as u32;
+        let after = |p: usize| ((p as u32 + self.offset) % n) as usize;
+        Self {
+            offset: (self.offset + next.offset) % n,
+            passes: (0..n as usize)
+                .map(|p| self.passes[p] + next.passes[after(p)])
+                .collect(),
+            landings: (0..n as usize)
+                .map(|p| self.landings[p] + next.landings[after(p)])
+                .collect(),
+        }
+    }
+}
+
+type Link = Option<Box<Node>>;
+
+/// A rotation in a treap ordered by position in the list, with its own summary and its
+/// subtree's.
+#[derive(Debug, Clone)]
+struct Node {
+    rot: Rot,
+    priority: u64,
+    size: usize,
+    own: Summary,
+    summary: Summary,
+    left: Link,
+    right: Link,
+}
+
+fn size(link: &Link) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+impl Node {
+    fn new(dial: &Dial, rot: Rot, priority: u64) -> Self {
+        let own = Summary::rot(dial, rot);
+        Self {
+            rot,
+            priority,
+            size: 1,
+            summary: own.clone(),
+            own,
+            left: None,
+            right: None,
+        }
+    }
+
+    /// Recomputes the size and summary from the children.
+    fn update(&mut self) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+        self.summary = match (&self.left, &self.right) {
+            (None, None) => self.own.clone(),
+            (Some(left), None) => left.summary.then(&self.own),
+            (None, Some(right)) => self.own.then(&right.summary),
+            (Some(left), Some(right)) => left.summary.then(&self.own).then(&right.summary),
+        };
+    }
+
+    fn set(&mut self, dial: &Dial, i: usize, rot: Rot) -> Rot {
+        let left = size(&self.left);
+        let old = match i.cmp(&left) {
+            Ordering::Less => self.left.as_mut().unwrap().set(dial, i, rot),
+            Ordering::Equal => {
+                self.own = Summary::rot(dial, rot);
+                std::mem::replace(&mut self.rot, rot)
+            }
+            Ordering::Greater => self.right.as_mut().unwrap().set(dial, i - left - 1, rot),
+        };
+        self.update();
+        old
+    }
+}
+
+/// The first `k` rotations of `link`, and the rest.
+fn split(link: Link, k: usize) -> (Link, Link) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+    let left = size(&node.left);
+    if k <= left {
+        let (first, rest) = split(node.left.take(), k);
+        node.left = rest;
+        node.update();
+        (first, Some(node))
+    } else {
+        let (first, rest) = split(node.right.take(), k - left - 1);
+        node.right = first;
+        node.update();
+        (Some(node), rest)
+    }
+}
+
+/// `a`'s rotations followed by `b`'s.
+fn merge(a: Link, b: Link) -> Link {
+    match (a, b) {
+        (None, b) => b,
+        (a, None) => a,
+        (Some(mut a), Some(mut b)) => {
+            if a.priority > b.priority {
+                a.right = merge(a.right.take(), Some(b));
+                a.update();
+                Some(a)
+            } else {
+                b.left = merge(Some(a), b.left.take());
+                b.update();
+                Some(b)
+            }
+        }
+    }
+}
+
+/// An editable list of rotations on a [`Dial`] that answers pass and landing counts over any
+/// range of steps in `O(log len)`.
+///
+/// It is a treap keyed by position in the list, whose nodes summarise their own rotation and
+/// their subtree's for every entry position, so it takes `O(len * positions)` memory. Replacing
+/// or inserting a rotation summarises it in `O(positions * targets)`, and every edit
+/// recombines the summaries on one path in `O(positions * log len)` expected time.
+#[derive(Debug, Clone)]
+pub struct Rotations {
+    dial: Dial,
+    root: Link,
+    /// Drives the node priorities, so the tree's shape doesn't depend on the edits.
+    seed: u64,
+}
+
+impl Rotations {
+    pub fn new(dial: Dial, rots: impl IntoIterator<Item = Rot>) -> Self {
+        let mut rotations = Self {
+            dial,
+            root: None,
+            seed: 0,
+        };
+        for rot in rots {
+            rotations.insert(rotations.len(), rot);
+        }
+        rotations
+    }
+
+    /// SplitMix64.
+    fn priority(&mut self) -> u64 {
+        self.seed = self.seed.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    pub fn dial(&self) -> &Dial {
+        &self.dial
+    }
+
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn get(&self, mut i: usize) -> Option<Rot> {
+        let mut link = self.root.as_deref();
+        while let Some(node) = link {
+            let left = size(&node.left);
+            match i.cmp(&left) {
+                Ordering::Less => link = node.left.as_deref(),
+                Ordering::Equal => return Some(node.rot),
+                Ordering::Greater => {
+                    i -= left + 1;
+                    link = node.right.as_deref();
+                }
+            }
+        }
+        None
+    }
+
+    /// The rotations, in order.
+    pub fn rots(&self) -> impl Iterator<Item = Rot> + '_ {
+        let mut stack = Vec::new();
+        let mut next = self.root.as_deref();
+        std::iter::from_fn(move || {
+            while let Some(node) = next {
+                stack.push(node);
+                next = node.left.as_deref();
+            }
+            let node = stack.pop()?;
+            next = node.right.as_deref();
+            Some(node.rot)
+        })
+    }
+
+    /// Replaces the rotation at `i`, returning the old one.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds.
+    pub fn set(&mut self, i: usize, rot: Rot) -> Rot {
+        self.check_index(i);
+        let root = self.root.as_mut().expect("the list isn't empty");
+        root.set(&self.dial, i, rot)
+    }
+
+    /// # Panics
+    ///
+    /// If `i > len`.
+    pub fn insert(&mut self, i: usize, rot: Rot) {
+        assert!(
+            i <= self.len(),
+            "insertion index {i} out of range for {} rotations",
+            self.len()
+        );
+        let priority = self.priority();
+        let node = Node::new(&self.dial, rot, priority);
+        let (first, rest) = split(self.root.take(), i);
+        let first = merge(first, Some(Box::new(node)));
+        self.root = merge(first, rest);
+    }
+
+    /// # Panics
+    ///
+    /// If `i` is out of bounds.
+    pub fn remove(&mut self, i: usize) -> Rot {
+        self.check_index(i);
+        let (first, rest) = split(self.root.take(), i);
+        let (node, rest) = split(rest, 1);
+        self.root = merge(first, rest);
+        node.expect("the index is in bounds").rot
+    }
+
+    fn check_index(&self, i: usize) {
+        assert!(
+            i < self.len(),
+            "index {i} out of range for {} rotations",
+            self.len()
+        );
+    }
+
+    /// Where the dial points after the first `steps` rotations.
+    pub fn position(&self, steps: usize) -> u32 {
+        self.prefix(steps).0
+    }
+
+    /// Clicks during the given steps that point the dial at a target.
+    pub fn passes(&self, steps: impl RangeBounds<usize>) -> u64 {
+        let (start, end) = self.bounds(steps);
+        self.prefix(end).1 - self.prefix(start).1
+    }
+
+    /// How many of the given steps end on a target.
+    pub fn landings(&self, steps: impl RangeBounds<usize>) -> u64 {
+        let (start, end) = self.bounds(steps);
+        self.prefix(end).2 - self.prefix(start).2
+    }
+
+    fn bounds(&self, steps: impl RangeBounds<usize>) -> (usize, usize) {
+        let start = match steps.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match steps.end_bound() {
+            Bound::Included(&i) => i + 1,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => self.len(),
+        };
+        assert!(
+            start <= end && end <= self.len(),
+            "steps {start}..{end} out of range for {} rotations",
+            self.len()
+        );
+        (start, end)
+    }
+
+    /// The position, passes and landings after the first `steps` rotations, folding the
+    /// summaries of the subtrees and nodes before them from left to right.
+    fn prefix(&self, steps: usize) -> (u32, u64, u64) {
+        let n = self.dial.positions();
+        let (mut pos, mut passes, mut landings) = (self.dial.start(), 0, 0);
+        let mut apply = |s: &Summary| {
+            passes += s.passes[pos as usize];
+            landings += s.landings[pos as usize];
+            pos = (pos + s.offset) % n;
+        };
+        let mut link = self.root.as_deref();
+        let mut remaining = steps;
+        while remaining > 0 {
+            let node = link.expect("steps are within the list");
+            if remaining == node.size {
+                apply(&node.summary);
+                break;
+            }
+            let left = size(&node.left);
+            if remaining <= left {
+                link = node.left.as_deref();
+                continue;
+            }
+            if let Some(left) = &node.left {
+                apply(&left.summary);
+            }
+            apply(&node.own);
+            remaining -= left + 1;
+            link = node.right.as_deref();
+        }
+        (pos, passes, landings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Direction, generator, parse};
+    use anyhow::Result;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    /// Counts from scratch with [`Dial::trace`].
+    fn expected(r: &Rotations, start: usize, end: usize) -> (u32, u64, u64) {
+        let rots: Vec<_> = r.rots().collect();
+        let steps: Vec<_> = r.dial().trace(&rots).collect();
+        let pos = steps[..end].last().map_or(r.dial().start(), |s| s.end);
+        let passes = steps[start..end].iter().map(|s| s.passes).sum();
+        let landings = steps[start..end].iter().filter(|s| s.landed).count() as u64;
+        (pos, passes, landings)
+    }
+
+    #[test]
+    fn test_example() -> Result<()> {
+        let input = parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82")?;
+        let mut r = Rotations::new(Dial::default(), input);
+        assert_eq!((r.landings(..), r.passes(..)), (3, 6));
+        assert_eq!((r.position(3), r.passes(1..3), r.landings(..=2)), (0, 1, 1));
+
+        assert_eq!(r.set(0, Rot(Direction::L, 50)), Rot(Direction::L, 68));
+        assert_eq!((r.position(1), r.passes(..1), r.landings(..1)), (0, 1, 1));
+        r.remove(0);
+        r.insert(0, Rot(Direction::R, 1000));
+        assert_eq!((r.position(1), r.passes(..1)), (50, 10));
+
+        let r = Rotations::new(Dial::default(), parse("R4294967295x101")?);
+        assert_eq!((r.landings(..), r.passes(..)), (5, 4337916968));
+        Ok(())
+    }
+
+    #[test]
+    fn test_edits() -> Result<()> {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for dial in [Dial::default(), Dial::new(60, 7, [0, 13, 59])?] {
+            let mut model = parse(&generator::generate(1, 30))?;
+            let mut r = Rotations::new(dial, model.clone());
+            for _ in 0..300 {
+                let direction = if rng.random_bool(0.5) {
+                    Direction::L
+                } else {
+                    Direction::R
+                };
+                let rot = Rot(direction, rng.random_range(0..400));
+                let i = rng.random_range(0..=r.len());
+                match rng.random_range(0..3) {
+                    0 => {
+                        r.insert(i, rot);
+                        model.insert(i, rot);
+                    }
+                    1 if i < r.len() => assert_eq!(r.remove(i), model.remove(i)),
+                    _ if i < r.len() => {
+                        assert_eq!(r.set(i, rot), model[i]);
+                        model[i] = rot;
+                    }
+                    _ => {}
+                }
+                assert!(r.rots().eq(model.iter().copied()));
+                assert_eq!(r.get(i), model.get(i).copied());
+                let end = rng.random_range(0..=r.len());
+                let start = rng.random_range(0..=end);
+                let (pos, passes, landings) = expected(&r, start, end);
+                assert_eq!(r.position(end), pos);
+                assert_eq!(r.passes(start..end), passes);
+                assert_eq!(r.landings(start..end), landings);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty() {
+        let r = Rotations::new(Dial::default(), []);
+        assert!(r.is_empty());
+        assert_eq!((r.position(0), r.passes(..), r.landings(..)), (50, 0, 0));
+    }
+}
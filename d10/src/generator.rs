@@ -0,0 +1,77 @@
+use rand::seq::IndexedRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// `size` machines, one per line, with 3 to 10 lights and up to 13 buttons each.
+///
+/// The lights are the ones some presses of the buttons other than the first turn on, and the
+/// joltages are what some presses of every button add up to, so every machine is solvable
+/// within what `task1` and `task2` search.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    (0..size.max(1))
+        .map(|_| {
+            let n_lights = rng.random_range(3..=10);
+            let lights: Vec<_> = (0..n_lights).collect();
+            let buttons: Vec<Vec<usize>> = (0..rng.random_range(2..=n_lights + 3))
+                .map(|_| {
+                    let n = rng.random_range(1..=n_lights);
+                    let mut button: Vec<_> = lights.choose_multiple(&mut rng, n).copied().collect();
+                    button.sort_unstable();
+                    button
+                })
+                .collect();
+
+            let mut on = vec![false; n_lights];
+            let mut joltage = vec![0; n_lights];
+            for (i, button) in buttons.iter().enumerate() {
+                let toggle = i > 0 && rng.random_bool(0.5);
+                let presses = rng.random_range(0..=10);
+                for &light in button {
+                    on[light] ^= toggle;
+                    joltage[light] += presses;
+                }
+            }
+
+            let on: String = on.iter().map(|&on| if on { '#' } else { '.' }).collect();
+            let join = |v: &[usize]| v.iter().map(usize::to_string).collect::<Vec<_>>().join(",");
+            let buttons: Vec<_> = buttons.iter().map(|b| format!("({})", join(b))).collect();
+            format!("[{on}] {} {{{}}}", buttons.join(" "), join(&joltage))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, task1, task2};
+    use anyhow::Result;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = parse(&generate(1, 20))?;
+        assert_eq!(input.len(), 20);
+        for m in &input {
+            let n = m.lights.len();
+            assert!((3..=10).contains(&n) && m.joltage.len() == n);
+            assert!((2..=n + 3).contains(&m.buttons.len()));
+            assert!(m.buttons.iter().flatten().all(|&light| light < n));
+        }
+        let buttons: usize = input.iter().map(|m| m.buttons.len()).sum();
+        assert!(task1(&input) < buttons);
+        // Every press adds at most 1 to a counter.
+        let highest: usize = input.iter().map(|m| m.joltage.iter().max().unwrap()).sum();
+        assert!(task2(&input) >= highest);
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_small() -> Result<()> {
+        let input = parse(&generate(0, 2))?;
+        assert_eq!(input[1], parse("[.#.] (1,2) (0,1,2) (0,2) {11,9,16}")?[0]);
+        // The second machine takes two presses for its lights, and 5 + 4 + 7 for its joltages.
+        assert_eq!((task1(&input), task2(&input)), (2 + 2, 39 + 16));
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::Result;
+use aoc::Solution;
+use itertools::Itertools;
+use microlp::{ComparisonOp, OptimizationDirection, Problem};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use std::fmt::Display;
+
+use winnow::combinator::{alt, cut_err, preceded, repeat, separated, seq, terminated};
+use winnow::error::{StrContext, StrContextValue::Description};
+use winnow::{Parser, ascii::dec_uint};
+
+pub mod generator;
+pub mod reference;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    pub lights: Vec<bool>,
+    /// The lights or counters each button toggles.
+    pub buttons: Vec<Vec<usize>>,
+    pub joltage: Vec<usize>,
+}
+
+fn parse_(input: &mut &str) -> winnow::ModalResult<Vec<Machine>> {
+    let expected = |c: char| StrContext::Expected(c.into());
+    let numbers = || {
+        let number = dec_uint::<_, usize, _>.context(StrContext::Expected(Description("a number")));
+        separated(1.., number, ',').map(|v: Vec<_>| v)
+    };
+    // Each bracketed group is committed to once it is opened, so errors inside it are reported
+    // where they happen instead of making the enclosing list stop early.
+    let light = alt(('.'.value(false), '#'.value(true)));
+    let mut parse_lights = preceded(
+        '['.context(expected('[')),
+        cut_err(terminated(repeat(1.., light), ']'.context(expected(']')))),
+    );
+    let button = preceded(
+        '(',
+        cut_err(terminated(numbers(), ')'.context(expected(')')))),
+    );
+    let mut parse_buttons = separated(1.., button, ' ');
+    let mut parse_joltage = preceded(
+        '{'.context(expected('{')),
+        cut_err(terminated(numbers(), '}'.context(expected('}')))),
+    );
+    let parse_machine = seq! {Machine {
+        lights: parse_lights,
+        _: ' '.context(expected(' ')),
+        buttons: parse_buttons,
+        _: ' '.context(expected(' ')),
+        joltage: parse_joltage,
+
+    }};
+    separated(1.., cut_err(parse_machine), "\n").parse_next(input)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Machine>> {
+    aoc::parse(parse_, input)
+}
+
+/// The fewest button presses that configure every machine's lights.
+pub fn task1(input: &[Machine]) -> usize {
+    input
+        .iter()
+        .map(|m| {
+            for n in 0..m.buttons.len() {
+                for comb in m.buttons.iter().combinations(n) {
+                    let mut light = vec![false; m.lights.len()];
+                    for button in comb {
+                        for i in button {
+                            light[*i] ^= true;
+                        }
+                    }
+                    if light.iter().zip(&m.lights).all(|(&a, &b)| a == b) {
+                        return n;
+                    }
+                }
+            }
+            unreachable!()
+        })
+        .sum()
+}
+
+/// The fewest button presses that reach every machine's joltage levels.
+pub fn task2(input: &[Machine]) -> usize {
+    input
+        .par_iter()
+        .map(|m| {
+            let mut prob = Problem::new(OptimizationDirection::Minimize);
+
+            let num_buttons = m.buttons.len();
+
+            // Create variables for each button (how many times to press it)
+            let vars: Vec<_> = (0..num_buttons)
+                .map(|_| prob.add_integer_var(1.0, (0, 300)))
+                .collect();
+
+            for (joltage_idx, &target) in m.joltage.iter().enumerate() {
+                let lhs = m
+                    .buttons
+                    .iter()
+                    .zip(&vars)
+                    .filter(|(button, _)| button.contains(&joltage_idx))
+                    .map(|(_, var)| (*var, 1.0));
+                prob.add_constraint(lhs, ComparisonOp::Eq, target as f64);
+            }
+
+            prob.solve().ok().unwrap().objective().round() as usize
+        })
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input<'a> = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Vec<Machine>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+
+    #[test]
+    fn test_task1() -> Result<()> {
+        let input = parse(INPUT)?;
+        assert_eq!(task1(&input), 7);
+        Ok(())
+    }
+
+    #[test]
+    fn test_task2() -> Result<()> {
+        let input = parse(INPUT)?;
+        assert_eq!(task2(&input), 33);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse("[.##.] (3) (1;3) {3,5,4,7}").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (1, 14));
+        assert_eq!(e.label, "expected `)`, found `;`");
+        let e = parse("[.#x.] (3) {3}").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.column, e.label.as_str()), (4, "expected `]`, found `x`"));
+    }
+
+    #[test]
+    fn test_main() -> Result<()> {
+        aoc::run::<Day10>()
+    }
+}
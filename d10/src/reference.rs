@@ -0,0 +1,113 @@
+use aoc::check::{Mismatch, differential, without_one};
+use std::ops::Range;
+
+use crate::{Machine, generator, parse};
+
+type Solver = fn(&Vec<Machine>) -> usize;
+
+/// Tries every set of buttons, pressing each at most once.
+pub fn task1(input: &[Machine]) -> usize {
+    input
+        .iter()
+        .map(|m| {
+            (0u32..1 << m.buttons.len())
+                .filter(|mask| {
+                    let mut lights = vec![false; m.lights.len()];
+                    for (i, button) in m.buttons.iter().enumerate() {
+                        if mask & 1 << i != 0 {
+                            button.iter().for_each(|&l| lights[l] ^= true);
+                        }
+                    }
+                    lights == m.lights
+                })
+                .map(|mask| mask.count_ones() as usize)
+                .min()
+                .expect("some set of buttons configures the lights")
+        })
+        .sum()
+}
+
+/// The fewest presses of `buttons` that bring `left` down to zero, trying every count of the
+/// first button that doesn't overshoot, then the rest in turn.
+fn fewest(buttons: &[Vec<usize>], left: &mut [usize]) -> Option<usize> {
+    let Some((button, rest)) = buttons.split_first() else {
+        return left.iter().all(|&l| l == 0).then_some(0);
+    };
+    let most = button.iter().map(|&c| left[c]).min().unwrap_or(0);
+    let mut best = None;
+    for presses in 0..=most {
+        button.iter().for_each(|&c| left[c] -= presses);
+        if let Some(n) = fewest(rest, left) {
+            best = Some(best.map_or(n + presses, |b: usize| b.min(n + presses)));
+        }
+        button.iter().for_each(|&c| left[c] += presses);
+    }
+    best
+}
+
+/// Tries every number of presses of every button.
+pub fn task2(input: &[Machine]) -> usize {
+    input
+        .iter()
+        .map(|m| fewest(&m.buttons, &mut m.joltage.clone()).expect("the joltages can be reached"))
+        .sum()
+}
+
+fn render(input: &[Machine]) -> String {
+    let join = |v: &[usize]| v.iter().map(usize::to_string).collect::<Vec<_>>().join(",");
+    let lines: Vec<_> = input
+        .iter()
+        .map(|m| {
+            let lights: String = m
+                .lights
+                .iter()
+                .map(|&on| if on { '#' } else { '.' })
+                .collect();
+            let buttons: Vec<_> = m.buttons.iter().map(|b| format!("({})", join(b))).collect();
+            format!("[{lights}] {} {{{}}}", buttons.join(" "), join(&m.joltage))
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// Compares both parts with exhaustive search on the generated machines with at most four
+/// buttons, out of up to 8.
+pub fn check(seeds: Range<u64>) -> Option<(u8, Mismatch<String, usize>)> {
+    let generate = |seed| {
+        let mut input = parse(&generator::generate(seed, 1 + seed as usize % 8)).unwrap();
+        input.retain(|m| m.buttons.len() <= 4);
+        input
+    };
+    let parts: [(Solver, Solver); 2] = [
+        (|input| crate::task1(input), |input| task1(input)),
+        (|input| crate::task2(input), |input| task2(input)),
+    ];
+    (1..).zip(parts).find_map(|(part, (solve, reference))| {
+        let shrink = |input: &Vec<Machine>| without_one(input, 1);
+        let m = differential(seeds.clone(), generate, shrink, solve, reference)?;
+        Some((part, m.map_input(|i| render(i))))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() -> anyhow::Result<()> {
+        let input = parse("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}")?;
+        assert_eq!((task1(&input), task2(&input)), (2, 10));
+        assert_eq!(
+            render(&input),
+            "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference() {
+        if let Some((part, m)) = check(0..2000) {
+            panic!("part {part}: {m}");
+        }
+    }
+}
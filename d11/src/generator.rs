@@ -0,0 +1,107 @@
+use rand::seq::{SliceRandom, index::sample};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
+
+/// A DAG of `size` devices plus `out`, listed in random order, containing `svr`, `you`, `fft`
+/// and `dac`.
+///
+/// Devices are placed in a random order and each one feeds 1 to 3 devices further along, so
+/// every device reaches `out`, which comes last, and the number of paths grows only
+/// polynomially with `size`.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let size = size.max(4);
+
+    let mut names = HashSet::from(["svr", "you", "fft", "dac", "out"].map(String::from));
+    let mut order = Vec::with_capacity(size + 1);
+    while order.len() < size - 4 {
+        let name: String = (0..3).map(|_| rng.random_range('a'..='z')).collect();
+        if names.insert(name.clone()) {
+            order.push(name);
+        }
+    }
+    for name in ["you", "fft", "dac"] {
+        let i = rng.random_range(0..=order.len());
+        order.insert(i, name.to_string());
+    }
+    order.insert(0, "svr".to_string());
+    order.push("out".to_string());
+
+    let mut lines: Vec<_> = (0..size)
+        .map(|i| {
+            let n_outputs = rng.random_range(1..=3.min(size - i));
+            let outputs: Vec<_> = sample(&mut rng, size - i, n_outputs)
+                .into_iter()
+                .map(|j| order[i + 1 + j].as_str())
+                .collect();
+            format!("{}: {}", order[i], outputs.join(" "))
+        })
+        .collect();
+    lines.shuffle(&mut rng);
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, task1, task2};
+    use anyhow::Result;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = generate(1, 200);
+        let devices = parse(&input)?;
+        assert_eq!(devices.len(), 200);
+        for name in ["svr", "you", "fft", "dac"] {
+            assert!(devices.contains_key(name), "no {name}");
+        }
+        assert!(
+            devices
+                .values()
+                .flatten()
+                .all(|&d| d == "out" || devices.contains_key(d))
+        );
+
+        // Taking devices that no remaining device feeds, one at a time, takes them all.
+        let mut inputs: HashMap<&str, usize> = devices.keys().map(|&d| (d, 0)).collect();
+        for &d in devices.values().flatten().filter(|&&d| d != "out") {
+            *inputs.get_mut(d).unwrap() += 1;
+        }
+        let mut ready: Vec<_> = inputs
+            .iter()
+            .filter(|&(_, &n)| n == 0)
+            .map(|(&d, _)| d)
+            .collect();
+        let mut taken = 0;
+        while let Some(device) = ready.pop() {
+            taken += 1;
+            for &d in devices[device].iter().filter(|&&d| d != "out") {
+                let n = inputs.get_mut(d).unwrap();
+                *n -= 1;
+                if *n == 0 {
+                    ready.push(d);
+                }
+            }
+        }
+        assert_eq!(taken, devices.len(), "the devices form a cycle");
+
+        assert!(task1(&devices) > 0);
+        task2(&devices);
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_small() -> Result<()> {
+        let input = generate(5, 6);
+        assert_eq!(
+            input,
+            "svr: out ffh\ndac: out\nfft: out dac\nffh: out fft dac\nyou: out\nsmv: dac out"
+        );
+        // Only svr, ffh, fft, dac, out passes both.
+        let devices = parse(&input)?;
+        assert_eq!((task1(&devices), task2(&devices)), (1, 1));
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use anyhow::Result;
+use aoc::Solution;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+
+use winnow::{
+    Parser,
+    ascii::alpha1,
+    combinator::{cut_err, separated, separated_pair},
+    error::{StrContext, StrContextValue::Description},
+};
+
+pub mod generator;
+pub mod reference;
+
+fn parse_<'a>(input: &mut &'a str) -> winnow::ModalResult<Vec<(&'a str, Vec<&'a str>)>> {
+    let name = || alpha1.context(StrContext::Expected(Description("a device name")));
+    let outputs = separated(1.., name(), " ").map(|v: Vec<_>| v);
+    let parse_device = separated_pair(
+        name(),
+        ": ".context(StrContext::Expected(": ".into())),
+        outputs,
+    );
+    separated(1.., cut_err(parse_device), "\n")
+        .map(|v: Vec<_>| v)
+        .parse_next(input)
+}
+
+/// Each device's outputs, borrowed from the input.
+pub type Devices<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+pub fn parse(input: &str) -> Result<Devices<'_>> {
+    aoc::parse(parse_, input).map(|v| v.into_iter().collect())
+}
+
+/// How many paths lead from `you` to `out`.
+pub fn task1(devices: &Devices) -> i32 {
+    let mut queue = VecDeque::new();
+
+    queue.push_back("you");
+    let mut counter = 0;
+    while let Some(device) = queue.pop_front() {
+        if device == "out" {
+            counter += 1;
+        }
+        if let Some(neighbors) = devices.get(device) {
+            for neighbor in neighbors {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    counter
+}
+
+// fft + dac, fft, dac, none
+type Rez = (usize, usize, usize, usize);
+fn get_count_for_device<'a>(
+    input: &'a str,
+    devices: &'a Devices,
+    mem: &mut HashMap<&'a str, Rez>,
+) -> Rez {
+    if let Some(ret) = mem.get(input) {
+        return *ret;
+    }
+    let Some(neighbors) = devices.get(input) else {
+        return (0, 0, 0, 0);
+    };
+    let (fft_dac, fft, dac, none) =
+        neighbors
+            .iter()
+            .fold((0, 0, 0, 0), |(fft_dac, fft, dac, none), neighbor| {
+                let (fd, f, d, n) = get_count_for_device(neighbor, devices, mem);
+                (fft_dac + fd, fft + f, dac + d, none + n)
+            });
+    let (fft_dac, fft, dac, none) = match input {
+        "fft" => (fft_dac + dac, fft + none, 0, 0),
+        "dac" => (fft_dac + fft, 0, dac + none, 0),
+        _ => (fft_dac, fft, dac, none),
+    };
+    mem.insert(input, (fft_dac, fft, dac, none));
+
+    (fft_dac, fft, dac, none)
+}
+
+/// How many paths from `svr` to `out` visit both `fft` and `dac`.
+pub fn task2(devices: &Devices) -> usize {
+    let mut mem = HashMap::new();
+    mem.insert("out", (0, 0, 0, 1));
+    get_count_for_device("svr", devices, &mut mem).0
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input<'a> = Devices<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task1() -> Result<()> {
+        let input = r"aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out";
+        let input = parse(input)?;
+        assert_eq!(task1(&input), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_task2() -> Result<()> {
+        let input = r"svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out";
+        let input = parse(input)?;
+        assert_eq!(task2(&input), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse("aaa: you hhh\nyou bbb").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (2, 4));
+        assert_eq!(e.label, "expected `: `, found ` `");
+    }
+
+    #[test]
+    fn test_main() -> Result<()> {
+        aoc::run::<Day11>()
+    }
+}
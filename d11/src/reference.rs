@@ -0,0 +1,63 @@
+use aoc::check::{Mismatch, differential, without_one};
+use std::ops::Range;
+
+use crate::{Devices, generator, parse};
+
+type Solver = fn(&String) -> usize;
+
+/// Walks every path from `device` to `out` one at a time, counting those that visit all of
+/// `via`.
+fn paths(devices: &Devices, device: &str, via: &[&str], seen: usize) -> usize {
+    let seen = seen + via.iter().filter(|&&v| v == device).count();
+    if device == "out" {
+        return (seen == via.len()) as usize;
+    }
+    (devices.get(device).into_iter().flatten())
+        .map(|next| paths(devices, next, via, seen))
+        .sum()
+}
+
+pub fn task1(devices: &Devices) -> usize {
+    paths(devices, "you", &[], 0)
+}
+
+pub fn task2(devices: &Devices) -> usize {
+    paths(devices, "svr", &["fft", "dac"], 0)
+}
+
+/// Compares both parts with path enumeration on graphs of up to 15 devices, dropping
+/// devices' lines to shrink them.
+pub fn check(seeds: Range<u64>) -> Option<(u8, Mismatch<String, usize>)> {
+    let generate = |seed| generator::generate(seed, 4 + seed as usize % 12);
+    let shrink = |input: &String| {
+        let lines: Vec<_> = input.lines().collect();
+        (without_one(&lines, 1).into_iter())
+            .map(|lines| lines.join("\n"))
+            .collect()
+    };
+    let parts: [(Solver, Solver); 2] = [
+        (
+            |input| crate::task1(&parse(input).unwrap()) as usize,
+            |input| task1(&parse(input).unwrap()),
+        ),
+        (
+            |input| crate::task2(&parse(input).unwrap()),
+            |input| task2(&parse(input).unwrap()),
+        ),
+    ];
+    (1..).zip(parts).find_map(|(part, (solve, reference))| {
+        differential(seeds.clone(), generate, shrink, solve, reference).map(|m| (part, m))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference() {
+        if let Some((part, m)) = check(0..2000) {
+            panic!("part {part}: {m}");
+        }
+    }
+}
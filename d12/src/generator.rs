@@ -0,0 +1,107 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Present;
+
+const N_PRESENTS: usize = 6;
+
+/// Six random present shapes followed by `size` regions.
+///
+/// Like the real puzzle input, each region either has room for every present in its own 3×3
+/// block or has fewer free cells than its presents cover, so the bounds in
+/// [`Fitter::solve`](crate::Fitter::solve) decide it without searching.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let presents: Vec<_> = (0..N_PRESENTS)
+        .map(|_| {
+            let mut shape = [[false; 3]; 3];
+            for row in &mut shape {
+                for cell in row {
+                    *cell = rng.random_bool(0.7);
+                }
+            }
+            // The centre keeps every shape non-empty.
+            shape[1][1] = true;
+            Present { shape }
+        })
+        .collect();
+    let cells: Vec<_> = presents
+        .iter()
+        .map(|p| p.into_iter().filter(|&c| c).count())
+        .collect();
+
+    let mut blocks = Vec::new();
+    for (i, present) in presents.iter().enumerate() {
+        let rows: Vec<String> = present
+            .shape
+            .iter()
+            .map(|row| row.iter().map(|&c| if c { '#' } else { '.' }).collect())
+            .collect();
+        blocks.push(format!("{i}:\n{}", rows.join("\n")));
+    }
+
+    let regions: Vec<_> = (0..size.max(1))
+        .map(|_| {
+            let (width, height) = (rng.random_range(3..=50), rng.random_range(3..=50));
+            let mut n_presents = [0; N_PRESENTS];
+            if rng.random_bool(0.5) {
+                for _ in 0..rng.random_range(1..=(width / 3) * (height / 3)) {
+                    n_presents[rng.random_range(0..N_PRESENTS)] += 1;
+                }
+            } else {
+                let mut covered = 0;
+                while covered <= width * height {
+                    let i = rng.random_range(0..N_PRESENTS);
+                    n_presents[i] += 1;
+                    covered += cells[i];
+                }
+            }
+            let n_presents: Vec<_> = n_presents.iter().map(usize::to_string).collect();
+            format!("{width}x{height}: {}", n_presents.join(" "))
+        })
+        .collect();
+    blocks.push(regions.join("\n"));
+    blocks.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, task1};
+    use anyhow::Result;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = parse(&generate(1, 100))?;
+        let (presents, regions) = &input;
+        assert_eq!((presents.len(), regions.len()), (N_PRESENTS, 100));
+        let cells: Vec<_> = presents
+            .iter()
+            .map(|p| p.into_iter().filter(|&c| c).count())
+            .collect();
+        for r in regions {
+            assert!(
+                (3..=50).contains(&r.width) && (3..=50).contains(&r.height),
+                "{r:?}"
+            );
+            assert_eq!(r.n_presents.len(), N_PRESENTS);
+            let blocks = (r.width / 3) * (r.height / 3);
+            let covered: usize = cells.iter().zip(&r.n_presents).map(|(c, n)| c * n).sum();
+            let count: usize = r.n_presents.iter().sum();
+            assert!(count <= blocks || covered > r.width * r.height, "{r:?}");
+        }
+        let fit = task1(&input);
+        assert!(0 < fit && fit < 100);
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_small() -> Result<()> {
+        let input = generate(0, 3);
+        let regions = "38x24: 10 8 15 9 9 6\n32x43: 38 40 42 37 26 45\n22x12: 5 5 6 5 2 5";
+        assert!(input.ends_with(regions));
+        // 57 and 28 presents get a block each; the 228 cover 1378 cells, out of 1376.
+        assert_eq!(task1(&parse(&input)?), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,277 @@
+use anyhow::{Result, anyhow};
+use aoc::{Grid, Solution};
+use std::fmt::Display;
+use std::iter::Flatten;
+
+use winnow::combinator::{alt, cut_err, repeat, separated, separated_pair, seq};
+use winnow::error::{StrContext, StrContextValue::Description};
+use winnow::{Parser, ascii::dec_uint};
+
+pub mod generator;
+pub mod reference;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Present {
+    pub shape: [[bool; 3]; 3],
+}
+
+impl Present {
+    fn rotate(&mut self) {
+        let mut new_shape = [[false; 3]; 3];
+        for (i, row) in self.shape.iter().enumerate() {
+            for (j, &v) in row.iter().enumerate() {
+                new_shape[j][2 - i] = v;
+            }
+        }
+        self.shape = new_shape;
+    }
+
+    fn flip(&mut self) {
+        let mut new_shape = [[false; 3]; 3];
+        for (i, row) in self.shape.iter().enumerate() {
+            new_shape[2 - i] = *row;
+        }
+        self.shape = new_shape;
+    }
+}
+
+impl IntoIterator for Present {
+    type Item = bool;
+    type IntoIter = Flatten<std::array::IntoIter<[bool; 3], 3>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.shape.into_iter().flatten()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub height: usize,
+    pub width: usize,
+    /// How many of each present, by index, must fit.
+    pub n_presents: Vec<usize>,
+}
+
+pub type Input = (Vec<Present>, Vec<Region>);
+
+fn parse_(input: &mut &str) -> winnow::ModalResult<Input> {
+    let expected = |c: char| StrContext::Expected(c.into());
+    let number = || dec_uint::<_, usize, _>.context(StrContext::Expected(Description("a number")));
+    let cell = || {
+        alt(('#'.value(true), '.'.value(false)))
+            .context(expected('#'))
+            .context(expected('.'))
+    };
+    // Only commit to a present after its `N:` header, so the `WxH:` regions end the list.
+    let parse_present = seq! {Present {
+        _: number(),
+        _: ":\n",
+        shape: cut_err(separated(
+            3,
+            repeat(3, cell()).map(|v: Vec<bool>| [v[0], v[1], v[2]]),
+            '\n'.context(expected('\n')),
+        ).map(|v: Vec<[bool; 3]>| [v[0], v[1], v[2]])),
+    }};
+    let parse_presents = separated(1.., parse_present, "\n\n");
+
+    let parse_region = seq! {Region {
+        width: number(),
+        _: 'x'.context(expected('x')),
+        height: number(),
+        _: ": ".context(StrContext::Expected(": ".into())),
+        n_presents: separated(1.., number(), ' ').map(|v: Vec<_>| v),
+    }};
+    let parse_regions = separated(1.., cut_err(parse_region), "\n");
+
+    let blank_line = "\n\n".context(StrContext::Expected(Description("a blank line")));
+    separated_pair(parse_presents, blank_line, parse_regions).parse_next(input)
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    aoc::parse(parse_, input)
+}
+
+/// Tries to pack a region's presents, rotated or flipped, into its grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fitter<'a> {
+    presents: &'a [Present],
+    n_presents: Vec<usize>,
+    region: Grid<bool>,
+}
+
+impl<'a> Fitter<'a> {
+    pub fn new(
+        presents: &'a [Present],
+        Region {
+            height,
+            width,
+            n_presents,
+        }: Region,
+    ) -> Self {
+        Self {
+            presents,
+            n_presents,
+            region: Grid::new(width, height, false),
+        }
+    }
+
+    fn iter_present(
+        &mut self,
+        x: usize,
+        y: usize,
+        present: Present,
+    ) -> impl Iterator<Item = &mut bool> + use<'_> {
+        self.region
+            .rows_mut()
+            .skip(y)
+            .take(3)
+            .flat_map(move |row| row[x..x + 3].iter_mut())
+            .zip(present)
+            .filter(|(_, p)| *p)
+            .map(|(r, _)| r)
+    }
+
+    /// Whether all the presents fit, checking cheap bounds before searching.
+    pub fn solve(&mut self) -> bool {
+        if self
+            .presents
+            .iter()
+            .zip(&self.n_presents)
+            .map(|(p, n)| p.into_iter().filter(|&p| p).count() * n)
+            .sum::<usize>()
+            > self.region.width() * self.region.height()
+        {
+            return false;
+        }
+
+        if (self.region.width() / 3) * (self.region.height() / 3) >= self.n_presents.iter().sum() {
+            return true;
+        }
+
+        self.solve_bruteforce()
+    }
+
+    fn solve_bruteforce(&mut self) -> bool {
+        let Some(present_i) = self.n_presents.iter().position(|n| *n > 0) else {
+            return true;
+        };
+        self.n_presents[present_i] -= 1;
+        let mut present = self.presents[present_i];
+
+        for _n_flip in 0..2 {
+            for _n_rot in 0..4 {
+                for x in 0..=self.region.width() - 3 {
+                    for y in 0..=self.region.height() - 3 {
+                        if self.iter_present(x, y, present).all(|v| !*v) {
+                            for v in self.iter_present(x, y, present) {
+                                *v = true;
+                            }
+                            if self.solve_bruteforce() {
+                                return true;
+                            }
+                            for v in self.iter_present(x, y, present) {
+                                *v = false;
+                            }
+                        }
+                    }
+                }
+                present.rotate();
+            }
+            present.flip()
+        }
+
+        self.n_presents[present_i] += 1;
+        false
+    }
+}
+
+/// How many regions can fit all of their presents.
+pub fn task1((presents, regions): &Input) -> usize {
+    regions
+        .iter()
+        .map(|r| Fitter::new(presents, r.clone()).solve() as usize)
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const HAS_PART2: bool = false;
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task1(input))
+    }
+
+    fn part2(_: &Self::Input<'_>) -> Result<impl Display> {
+        Err::<usize, _>(anyhow!("day 12 has no second part"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //     const INPUT: &str = r"0:
+    // ###
+    // ##.
+    // ##.
+
+    // 1:
+    // ###
+    // ##.
+    // .##
+
+    // 2:
+    // .##
+    // ###
+    // ##.
+
+    // 3:
+    // ##.
+    // ###
+    // ##.
+
+    // 4:
+    // ###
+    // #..
+    // ###
+
+    // 5:
+    // ###
+    // .#.
+    // ###
+
+    // 4x4: 0 0 0 0 2 0
+    // 12x5: 1 0 1 0 2 2
+    // 12x5: 1 0 1 0 3 2";
+
+    // #[test]
+    // fn test_task1() -> Result<()> {
+    //     let input = parse(INPUT)?;
+    //     assert_eq!(task1(&input), 2);
+    //     Ok(())
+    // }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse("0:\n###\n#x.\n##.\n\n4x4: 1").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (3, 2));
+        assert_eq!(e.label, "expected `#` or `.`, found `x`");
+        let e = parse("0:\n###\n##.\n##.\n\n4x4 1").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (6, 4));
+        assert_eq!(e.label, "expected `: `, found ` `");
+    }
+
+    #[test]
+    fn test_main() -> Result<()> {
+        aoc::run::<Day12>()
+    }
+}
@@ -0,0 +1,157 @@
+use aoc::check::{Mismatch, differential, without_one};
+use std::ops::Range;
+
+use crate::{Input, Present, Region, generator, parse};
+
+/// A present's distinct rotations and flips, each as the offsets of its cells from its first
+/// cell in reading order.
+fn orientations(present: &Present) -> Vec<Vec<(isize, isize)>> {
+    let mut shape = present.shape;
+    let mut all: Vec<Vec<(isize, isize)>> = Vec::new();
+    for _ in 0..2 {
+        for _ in 0..4 {
+            let cells: Vec<_> = (0..3)
+                .flat_map(|y| (0..3).map(move |x| (x, y)))
+                .filter(|&(x, y)| shape[y as usize][x as usize])
+                .collect();
+            let (ax, ay) = cells[0];
+            let offsets: Vec<_> = cells.iter().map(|&(x, y)| (x - ax, y - ay)).collect();
+            if !all.contains(&offsets) {
+                all.push(offsets);
+            }
+            shape = std::array::from_fn(|y| std::array::from_fn(|x| shape[2 - x][y]));
+        }
+        shape.reverse();
+    }
+    all
+}
+
+struct Packer {
+    shapes: Vec<Vec<Vec<(isize, isize)>>>,
+    width: usize,
+    filled: Vec<bool>,
+}
+
+impl Packer {
+    /// Fills the region in reading order: the first free cell either stays empty, while
+    /// there's room to spare, or holds the first cell of some present.
+    fn pack(&mut self, from: usize, left: &mut [usize], spare: usize) -> bool {
+        if left.iter().all(|&n| n == 0) {
+            return true;
+        }
+        let Some(cell) = (from..self.filled.len()).find(|&c| !self.filled[c]) else {
+            return false;
+        };
+        let (x, y) = ((cell % self.width) as isize, (cell / self.width) as isize);
+        let height = (self.filled.len() / self.width) as isize;
+        for kind in 0..left.len() {
+            if left[kind] == 0 {
+                continue;
+            }
+            for o in 0..self.shapes[kind].len() {
+                let cells: Option<Vec<_>> = (self.shapes[kind][o].iter())
+                    .map(|&(dx, dy)| {
+                        let (cx, cy) = (x + dx, y + dy);
+                        let inside = (0..self.width as isize).contains(&cx) && cy < height;
+                        let c = (cy * self.width as isize + cx) as usize;
+                        (inside && !self.filled[c]).then_some(c)
+                    })
+                    .collect();
+                let Some(cells) = cells else {
+                    continue;
+                };
+                cells.iter().for_each(|&c| self.filled[c] = true);
+                left[kind] -= 1;
+                let packed = self.pack(cell + 1, left, spare);
+                left[kind] += 1;
+                cells.iter().for_each(|&c| self.filled[c] = false);
+                if packed {
+                    return true;
+                }
+            }
+        }
+        spare > 0 && {
+            self.filled[cell] = true;
+            let packed = self.pack(cell + 1, left, spare - 1);
+            self.filled[cell] = false;
+            packed
+        }
+    }
+}
+
+/// Whether the region's presents fit, searching cell by cell.
+fn fits(presents: &[Present], region: &Region) -> bool {
+    let cells = |p: &Present| p.into_iter().filter(|&c| c).count();
+    let needed: usize = (presents.iter().zip(&region.n_presents))
+        .map(|(p, n)| cells(p) * n)
+        .sum();
+    let Some(spare) = (region.width * region.height).checked_sub(needed) else {
+        return false;
+    };
+    let mut packer = Packer {
+        shapes: presents.iter().map(orientations).collect(),
+        width: region.width,
+        filled: vec![false; region.width * region.height],
+    };
+    packer.pack(0, &mut region.n_presents.clone(), spare)
+}
+
+pub fn task1((presents, regions): &Input) -> usize {
+    regions.iter().filter(|r| fits(presents, r)).count()
+}
+
+fn render((presents, regions): &Input) -> String {
+    let mut blocks: Vec<_> = (presents.iter().enumerate())
+        .map(|(i, p)| {
+            let rows: Vec<String> = (p.shape.iter())
+                .map(|row| row.iter().map(|&c| if c { '#' } else { '.' }).collect())
+                .collect();
+            format!("{i}:\n{}", rows.join("\n"))
+        })
+        .collect();
+    let regions: Vec<_> = (regions.iter())
+        .map(|r| {
+            let counts: Vec<_> = r.n_presents.iter().map(usize::to_string).collect();
+            format!("{}x{}: {}", r.width, r.height, counts.join(" "))
+        })
+        .collect();
+    blocks.push(regions.join("\n"));
+    blocks.join("\n\n")
+}
+
+/// Compares the only part with a cell-by-cell search on up to 4 regions, dropping regions to
+/// shrink them.
+pub fn check(seeds: Range<u64>) -> Option<(u8, Mismatch<String, usize>)> {
+    let generate = |seed| parse(&generator::generate(seed, 1 + seed as usize % 4)).unwrap();
+    let shrink = |(presents, regions): &Input| {
+        (without_one(regions, 1).into_iter())
+            .map(|regions| (presents.clone(), regions))
+            .collect()
+    };
+    let m = differential(seeds, generate, shrink, crate::task1, task1)?;
+    Some((1, m.map_input(render)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fits() -> anyhow::Result<()> {
+        let u = "0:\n#.#\n#.#\n###";
+        // A U's sides each take at least two cells of a column, so two can't share one.
+        let regions = "6x3: 2\n5x3: 2\n3x3: 1\n2x6: 1";
+        let input = parse(&format!("{u}\n\n{regions}"))?;
+        let fit: Vec<_> = input.1.iter().map(|r| fits(&input.0, r)).collect();
+        assert_eq!(fit, [true, false, true, false]);
+        assert_eq!(render(&input), format!("{u}\n\n{regions}"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference() {
+        if let Some((part, m)) = check(0..2000) {
+            panic!("part {part}: {m}");
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::rule::{check_base, to_digits};
+use crate::{IdRule, T, Totals};
+
+/// Reads an ID's digits, most significant first and without leading zeros, to decide whether
+/// it matches. IDs start at 1, so 0 never does.
+pub trait DigitAutomaton {
+    type State: Clone + Eq + Hash;
+
+    /// Between 2 and 36; [`IdQuery::totals`] fails on any other base.
+    fn base(&self) -> u32 {
+        10
+    }
+    fn start(&self) -> Self::State;
+    /// The state after reading `digit`, or `None` if no ID that goes on this way matches.
+    fn step(&self, state: &Self::State, digit: u32) -> Option<Self::State>;
+    fn accepts(&self, state: &Self::State) -> bool;
+}
+
+/// Counts and sums the IDs in `start..=end` that match.
+pub trait IdQuery {
+    fn totals(&self, start: T, end: T) -> Result<Totals>;
+}
+
+impl IdQuery for IdRule {
+    fn totals(&self, start: T, end: T) -> Result<Totals> {
+        Ok(IdRule::totals(self, start, end))
+    }
+}
+
+/// Digit DP: the IDs up to `end` less those below `start`, with the suffixes after each
+/// automaton state counted once per length. Takes `O(digits * states * base)`.
+///
+/// # Errors
+///
+/// If the automaton's base isn't between 2 and 36.
+impl<A: DigitAutomaton> IdQuery for A {
+    fn totals(&self, start: T, end: T) -> Result<Totals> {
+        check_base(self.base()).context("invalid digit automaton")?;
+        if start > end {
+            return Ok(Totals::default());
+        }
+        let mut dp = Dp {
+            automaton: self,
+            base: self.base() as u128,
+            memo: HashMap::new(),
+        };
+        let below = start.checked_sub(1).map(|s| dp.up_to(s));
+        Ok(dp.up_to(end) - below.unwrap_or_default())
+    }
+}
+
+struct Dp<'a, A: DigitAutomaton> {
+    automaton: &'a A,
+    base: u128,
+    memo: HashMap<(u32, A::State), Totals>,
+}
+
+impl<A: DigitAutomaton> Dp<'_, A> {
+    /// The accepted `len`-digit suffixes after `state`, leading zeros and all, as numbers.
+    fn suffixes(&mut self, len: u32, state: &A::State) -> Totals {
+        if len == 0 {
+            let count = self.automaton.accepts(state) as u128;
+            return Totals { count, sum: 0 };
+        }
+        if let Some(&totals) = self.memo.get(&(len, state.clone())) {
+            return totals;
+        }
+        let mut totals = Totals::default();
+        for d in 0..self.base as u32 {
+            if let Some(next) = self.automaton.step(state, d) {
+                totals += self.after(d as u128, len - 1, &next);
+            }
+        }
+        self.memo.insert((len, state.clone()), totals);
+        totals
+    }
+
+    /// The accepted IDs that start with `prefix`, leaving the automaton in `state`, and go on
+    /// for `len` more digits.
+    fn after(&mut self, prefix: u128, len: u32, state: &A::State) -> Totals {
+        let suffixes = self.suffixes(len, state);
+        Totals {
+            count: suffixes.count,
+            sum: prefix * self.base.pow(len) * suffixes.count + suffixes.sum,
+        }
+    }
+
+    /// The accepted IDs in `1..=x`: all shorter ones, then those that follow `x`'s digits for
+    /// a while and branch off below it.
+    fn up_to(&mut self, x: T) -> Totals {
+        if x == 0 {
+            return Totals::default();
+        }
+        let (a, base) = (self.automaton, self.base as u32);
+        let digits = to_digits(base, x);
+        let n = digits.len() as u32;
+
+        let start = a.start();
+        let mut totals = Totals::default();
+        for len in 1..n {
+            for d in 1..base {
+                if let Some(next) = a.step(&start, d) {
+                    totals += self.after(d as u128, len - 1, &next);
+                }
+            }
+        }
+        let (mut state, mut prefix) = (start, 0);
+        for (i, digit) in (0..).zip(digits.into_iter().map(u32::from)) {
+            for d in (i == 0) as u32..digit {
+                if let Some(next) = a.step(&state, d) {
+                    totals += self.after(prefix * self.base + d as u128, n - i - 1, &next);
+                }
+            }
+            let Some(next) = a.step(&state, digit) else {
+                return totals;
+            };
+            state = next;
+            prefix = prefix * self.base + digit as u128;
+        }
+        if a.accepts(&state) {
+            totals += Totals::from(x);
+        }
+        totals
+    }
+}
+
+/// IDs in which no digit appears twice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DistinctDigits {
+    base: u32,
+}
+
+impl DistinctDigits {
+    pub fn new(base: u32) -> Result<Self> {
+        check_base(base)?;
+        Ok(Self { base })
+    }
+}
+
+impl DigitAutomaton for DistinctDigits {
+    /// The digits seen so far, as bits.
+    type State = u64;
+
+    fn base(&self) -> u32 {
+        self.base
+    }
+
+    fn start(&self) -> u64 {
+        0
+    }
+
+    fn step(&self, &seen: &u64, digit: u32) -> Option<u64> {
+        (seen & 1 << digit == 0).then_some(seen | 1 << digit)
+    }
+
+    fn accepts(&self, _: &u64) -> bool {
+        true
+    }
+}
+
+/// IDs whose digits never decrease, like `1123`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonDecreasing {
+    base: u32,
+}
+
+impl NonDecreasing {
+    pub fn new(base: u32) -> Result<Self> {
+        check_base(base)?;
+        Ok(Self { base })
+    }
+}
+
+impl DigitAutomaton for NonDecreasing {
+    /// The last digit.
+    type State = u32;
+
+    fn base(&self) -> u32 {
+        self.base
+    }
+
+    fn start(&self) -> u32 {
+        0
+    }
+
+    fn step(&self, &last: &u32, digit: u32) -> Option<u32> {
+        (digit >= last).then_some(digit)
+    }
+
+    fn accepts(&self, _: &u32) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decimal IDs whose digits add up to a multiple of 7.
+    struct DigitSum;
+
+    impl DigitAutomaton for DigitSum {
+        type State = u32;
+
+        fn start(&self) -> u32 {
+            0
+        }
+
+        fn step(&self, sum: &u32, digit: u32) -> Option<u32> {
+            Some((sum + digit) % 7)
+        }
+
+        fn accepts(&self, &sum: &u32) -> bool {
+            sum == 0
+        }
+    }
+
+    /// [`NonDecreasing`] in whatever base it's given.
+    struct AnyBase(u32);
+
+    impl DigitAutomaton for AnyBase {
+        type State = u32;
+
+        fn base(&self) -> u32 {
+            self.0
+        }
+
+        fn start(&self) -> u32 {
+            0
+        }
+
+        fn step(&self, &last: &u32, digit: u32) -> Option<u32> {
+            (digit >= last).then_some(digit)
+        }
+
+        fn accepts(&self, _: &u32) -> bool {
+            true
+        }
+    }
+
+    fn scan<A: DigitAutomaton>(a: &A, start: T, end: T) -> Totals {
+        let matches = |id: T| {
+            let digits = to_digits(a.base(), id);
+            let state = digits
+                .iter()
+                .try_fold(a.start(), |s, &d| a.step(&s, d as u32));
+            state.is_some_and(|s| a.accepts(&s))
+        };
+        (start.max(1)..=end)
+            .filter(|&id| matches(id))
+            .map(Totals::from)
+            .sum()
+    }
+
+    #[test]
+    fn test_scan() -> Result<()> {
+        let ranges = [
+            (0, 0),
+            (0, 3000),
+            (9, 10),
+            (987, 1234),
+            (5000, 4000),
+            (65530, 66000),
+        ];
+        for (start, end) in ranges {
+            for base in [2, 10, 16] {
+                let distinct = DistinctDigits::new(base)?;
+                assert_eq!(distinct.totals(start, end)?, scan(&distinct, start, end));
+                let sorted = NonDecreasing::new(base)?;
+                assert_eq!(sorted.totals(start, end)?, scan(&sorted, start, end));
+            }
+            assert_eq!(DigitSum.totals(start, end)?, scan(&DigitSum, start, end));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_wide() -> Result<()> {
+        let distinct = DistinctDigits::new(10)?.totals(1, 10u64.pow(10))?;
+        assert_eq!(distinct.count, 8877690);
+        // Choosing a multiset of 1 to 10 digits from 1-9 and sorting it.
+        let sorted = NonDecreasing::new(10)?.totals(1, 10u64.pow(10) - 1)?;
+        assert_eq!(sorted.count, 92377);
+        let end = 10u64.pow(6) - 1;
+        assert_eq!(DigitSum.totals(0, end)?, scan(&DigitSum, 0, end));
+        assert!(DistinctDigits::new(37).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_base() -> Result<()> {
+        assert_eq!(
+            AnyBase(10).totals(0, 99)?,
+            NonDecreasing { base: 10 }.totals(0, 99)?
+        );
+        for base in [0, 1, 37] {
+            let e = AnyBase(base).totals(0, 99).unwrap_err();
+            let expected = format!("invalid digit automaton: base {base} is not between 2 and 36");
+            assert_eq!(format!("{e:#}"), expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_rule() -> Result<()> {
+        let rule = IdRule::exactly(2);
+        assert_eq!(IdQuery::totals(&rule, 95, 1012)?, rule.totals(95, 1012));
+        // Up to 10 every ID has distinct digits and is one copy of itself, so the two only
+        // disagree if one of them counts 0.
+        let every = IdRule::repeated(1..=1);
+        for end in [0, 9, 10] {
+            let distinct = DistinctDigits::new(10)?.totals(0, end)?;
+            assert_eq!(distinct, every.totals(0, end), "0-{end}");
+        }
+        assert_eq!(DistinctDigits::new(10)?.totals(0, 9)?.count, 9);
+        Ok(())
+    }
+}
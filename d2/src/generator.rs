@@ -0,0 +1,53 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::T;
+
+/// `size` comma-separated ranges of up to 10 000 IDs each, with IDs of up to 10 digits.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    (0..size.max(1))
+        .map(|_| {
+            let digits = rng.random_range(1..=10);
+            let start: T = rng.random_range(10u64.pow(digits - 1)..10u64.pow(digits));
+            let end = start + rng.random_range(0..10_000);
+            format!("{start}-{end}")
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IdRule, Semantics, parse, task1};
+    use anyhow::Result;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = parse(&generate(1, 50))?;
+        assert_eq!(input.len(), 50);
+        for &(start, end) in &input {
+            assert!(start <= end && end - start < 10_000, "{start}-{end}");
+            assert!((1..=10_000_000_000).contains(&start), "{start}-{end}");
+        }
+        assert!(
+            task1(&input, &IdRule::exactly(2), Semantics::Set)?
+                <= task1(&input, &IdRule::repeated(2..=10), Semantics::Set)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_small() -> Result<()> {
+        let input = generate(0, 3);
+        assert_eq!(input, "7568828-7573901,1541540-1550331,473295-481530");
+        let input = parse(&input)?;
+        // 473473 to 481481, then 474747 too.
+        let doubled = 1001 * (473..=481).sum::<T>();
+        assert_eq!(task1(&input, &IdRule::exactly(2), Semantics::Set)?, doubled);
+        let repeated = task1(&input, &IdRule::repeated(2..=10), Semantics::Set)?;
+        assert_eq!(repeated, doubled + 474747);
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc::Solution;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use winnow::combinator::{cut_err, separated, separated_pair};
+use winnow::error::{StrContext, StrContextValue::Description};
+use winnow::{Parser, ascii::dec_uint};
+
+mod automaton;
+pub mod generator;
+mod listing;
+pub mod reference;
+mod repeated;
+mod rule;
+pub use automaton::{DigitAutomaton, DistinctDigits, IdQuery, NonDecreasing};
+pub use listing::{Match, histogram, list};
+pub use repeated::Totals;
+pub use rule::{IdRule, Pattern};
+
+pub type T = u64;
+fn parse_(input: &mut &str) -> winnow::ModalResult<Vec<(T, T)>> {
+    let id = || dec_uint.context(StrContext::Expected(Description("an ID")));
+    let range = separated_pair(id(), '-'.context(StrContext::Expected('-'.into())), id());
+    separated(1.., cut_err(range), ',').parse_next(input)
+}
+
+/// Parses comma-separated inclusive `min-max` ID ranges.
+pub fn parse(input: &str) -> Result<Vec<(T, T)>> {
+    aoc::parse(parse_, input)
+}
+
+/// Whether an ID in several ranges counts once, or once for each of them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Semantics {
+    /// Overlapping ranges are merged first.
+    #[default]
+    Set,
+    Multiset,
+}
+
+/// Sums the IDs in `input` that `rule` finds invalid.
+pub fn task1(input: &[(T, T)], rule: &(impl IdQuery + Sync), semantics: Semantics) -> Result<T> {
+    let merged;
+    let ranges = match semantics {
+        Semantics::Set => {
+            merged = aoc::merge_ranges(input.to_vec());
+            &merged
+        }
+        Semantics::Multiset => input,
+    };
+    let totals: Totals = ranges
+        .par_iter()
+        .map(|&(start, end)| rule.totals(start, end))
+        .sum::<Result<_>>()?;
+    Ok(T::try_from(totals.sum)?)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input<'a> = Vec<(T, T)>;
+
+    fn parse(input: &str) -> Result<Vec<(T, T)>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display> {
+        task1(input, &IdRule::exactly(2), Semantics::Set)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display> {
+        task1(input, &IdRule::repeated(2..=6), Semantics::Set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const INPUT: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,
+    1698522-1698528,446443-446449,38593856-38593862,565653-565659,
+    824824821-824824827,2121212118-2121212124";
+
+    #[test]
+    fn test_task1() -> Result<()> {
+        let input = parse(&INPUT.replace([' ', '\n'], ""))?;
+        assert_eq!(
+            task1(&input, &IdRule::exactly(2), Semantics::Set)?,
+            1227775554
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_task2() -> Result<()> {
+        let input = parse(&INPUT.replace([' ', '\n'], ""))?;
+        assert_eq!(
+            task1(&input, &IdRule::repeated(2..=6), Semantics::Set)?,
+            4174379265
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlaps() -> Result<()> {
+        let input = parse("11-22,20-99,95-115,99-99")?;
+        assert_eq!(task1(&input, &IdRule::exactly(2), Semantics::Set)?, 11 * 45);
+        assert_eq!(
+            task1(&input, &IdRule::exactly(2), Semantics::Multiset)?,
+            11 * 45 + 22 + 99 * 2
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse("11-22,95+115").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (1, 9));
+        assert_eq!(e.label, "expected `-`, found `+`");
+    }
+
+    #[test]
+    fn test_main() -> Result<()> {
+        aoc::run::<Day2>()
+    }
+}
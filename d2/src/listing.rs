@@ -0,0 +1,200 @@
+use std::collections::BTreeMap;
+
+use crate::{IdRule, Semantics, T, Totals};
+
+/// An invalid ID, why it is invalid, and where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    pub id: T,
+    /// The block of digits, in the rule's base, that appears `reps` times.
+    pub block: String,
+    pub reps: usize,
+    /// The index of the input range the ID is in.
+    pub range: usize,
+}
+
+/// The parts of `start..=end` outside the sorted, disjoint `covered` ranges.
+fn gaps(covered: &[(T, T)], start: T, end: T) -> Vec<(T, T)> {
+    let mut gaps = vec![];
+    let mut from = start;
+    for &(min, max) in covered.iter().filter(|&&(_, max)| max >= start) {
+        if min > end {
+            break;
+        }
+        if min > from {
+            gaps.push((from, min - 1));
+        }
+        match max.checked_add(1) {
+            Some(next) if next <= end => from = next,
+            _ => return gaps,
+        }
+    }
+    if from <= end {
+        gaps.push((from, end));
+    }
+    gaps
+}
+
+/// Adds `start..=end` to `covered`, disjoint ranges keyed by their start with none adjacent, and
+/// returns the parts of it that were new.
+///
+/// Only the ranges it overlaps or touches are visited, and each is merged away once, so a
+/// range costs `O(log n)` plus the pieces it splits into.
+fn cover(covered: &mut BTreeMap<T, T>, start: T, end: T) -> Vec<(T, T)> {
+    if start > end {
+        return vec![];
+    }
+    let before = (covered.range(..start).next_back())
+        .filter(|&(_, &max)| max.saturating_add(1) >= start)
+        .map(|(&min, _)| min);
+    let touching: Vec<_> = (covered.range(before.unwrap_or(start)..=end.saturating_add(1)))
+        .map(|(&min, &max)| (min, max))
+        .collect();
+    let pieces = gaps(&touching, start, end);
+    for (min, _) in &touching {
+        covered.remove(min);
+    }
+    let min = touching.first().map_or(start, |&(min, _)| min.min(start));
+    let max = touching.last().map_or(end, |&(_, max)| max.max(end));
+    covered.insert(min, max);
+    pieces
+}
+
+/// Each range's index with the parts of it to look at: all of it, or for set semantics, the
+/// parts that no earlier range covers.
+fn pieces(input: &[(T, T)], semantics: Semantics) -> impl Iterator<Item = (usize, T, T)> + '_ {
+    let mut covered = BTreeMap::new();
+    input
+        .iter()
+        .enumerate()
+        .flat_map(move |(i, &(start, end))| {
+            let pieces = match semantics {
+                Semantics::Set => cover(&mut covered, start, end),
+                Semantics::Multiset => vec![(start, end)],
+            };
+            pieces.into_iter().map(move |(start, end)| (i, start, end))
+        })
+}
+
+/// The IDs in `input` that `rule` finds invalid, as [`task1`](crate::task1) counts them, each
+/// with its explanation. IDs are found as they are needed, range by range.
+pub fn list<'a>(
+    input: &'a [(T, T)],
+    rule: &'a IdRule,
+    semantics: Semantics,
+) -> impl Iterator<Item = Match> + 'a {
+    pieces(input, semantics).flat_map(move |(range, start, end)| {
+        rule.ids(start, end).map(move |id| {
+            let (block, reps) = rule.explain(id).expect("only matching IDs are listed");
+            Match {
+                id,
+                block,
+                reps,
+                range,
+            }
+        })
+    })
+}
+
+/// How many of the IDs that [`list`] yields repeat their block each number of times, and what
+/// they add up to, without listing them for repeated-block rules.
+pub fn histogram(input: &[(T, T)], rule: &IdRule, semantics: Semantics) -> BTreeMap<usize, Totals> {
+    let mut histogram = BTreeMap::new();
+    for (_, start, end) in pieces(input, semantics) {
+        for (reps, totals) in rule.histogram(start, end) {
+            *histogram.entry(reps).or_insert_with(Totals::default) += totals;
+        }
+    }
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Pattern, parse, task1};
+    use anyhow::Result;
+
+    #[test]
+    fn test_gaps() {
+        let covered = [(5, 10), (20, 30), (40, T::MAX)];
+        assert_eq!(gaps(&covered, 0, 25), [(0, 4), (11, 19)]);
+        assert_eq!(gaps(&covered, 6, 9), []);
+        assert_eq!(gaps(&covered, 11, 19), [(11, 19)]);
+        assert_eq!(gaps(&covered, 25, 50), [(31, 39)]);
+        assert_eq!(gaps(&[], 3, 2), []);
+    }
+
+    #[test]
+    fn test_cover() -> Result<()> {
+        let mut covered = BTreeMap::new();
+        assert_eq!(cover(&mut covered, 20, 30), [(20, 30)]);
+        assert_eq!(cover(&mut covered, 5, 10), [(5, 10)]);
+        assert_eq!(cover(&mut covered, 3, 2), []);
+        assert_eq!(cover(&mut covered, 0, 25), [(0, 4), (11, 19)]);
+        assert_eq!(cover(&mut covered, 31, 35), [(31, 35)]);
+        assert_eq!(cover(&mut covered, 40, T::MAX), [(40, T::MAX)]);
+        assert_eq!(cover(&mut covered, 1, T::MAX), [(36, 39)]);
+        assert_eq!(covered, BTreeMap::from([(0, T::MAX)]));
+
+        // Against merging every range so far again, on ranges squeezed together to overlap.
+        let input = parse(&crate::generator::generate(3, 300))?;
+        let (mut covered, mut merged) = (BTreeMap::new(), vec![]);
+        for &(start, end) in &input {
+            let (start, end) = (start % 1000, start % 1000 + (end - start) % 50);
+            assert_eq!(cover(&mut covered, start, end), gaps(&merged, start, end));
+            merged.push((start, end));
+            merged = aoc::merge_ranges(merged);
+            assert_eq!(covered.clone().into_iter().collect::<Vec<_>>(), merged);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_list() -> Result<()> {
+        let input = parse("95-115,11-22,100-1011,998-1012")?;
+        let rule = IdRule::repeated(2..=6);
+        let listed: Vec<_> = list(&input, &rule, Semantics::Set)
+            .map(|m| (m.id, m.block, m.reps, m.range))
+            .collect();
+        let expected = [
+            (99, "9", 2, 0),
+            (111, "1", 3, 0),
+            (11, "1", 2, 1),
+            (22, "2", 2, 1),
+            (222, "2", 3, 2),
+        ];
+        let expected: Vec<_> = (expected.into_iter())
+            .map(|(id, block, reps, range)| (id, block.to_string(), reps, range))
+            .collect();
+        assert_eq!(listed[..5], expected);
+        assert_eq!(listed.last(), Some(&(1010, "10".to_string(), 2, 2)));
+
+        for semantics in [Semantics::Set, Semantics::Multiset] {
+            let ids: T = list(&input, &rule, semantics).map(|m| m.id).sum();
+            assert_eq!(ids, task1(&input, &rule, semantics)?);
+            let histogram = histogram(&input, &rule, semantics);
+            let mut counts = BTreeMap::new();
+            for m in list(&input, &rule, semantics) {
+                *counts.entry(m.reps).or_insert(0) += 1;
+            }
+            let histogram: BTreeMap<_, _> = (histogram.into_iter())
+                .map(|(reps, totals)| (reps, totals.count))
+                .collect();
+            assert_eq!(histogram, counts);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy() -> Result<()> {
+        let input = [(1, T::MAX)];
+        let rule = IdRule::at_least(2);
+        let first: Vec<_> = list(&input, &rule, Semantics::Set).take(3).collect();
+        assert_eq!(first.iter().map(|m| m.id).collect::<Vec<_>>(), [11, 22, 33]);
+        let runs = IdRule::new(10, Pattern::Run(3))?;
+        let run = list(&input, &runs, Semantics::Set).next().unwrap();
+        assert_eq!((run.id, run.block, run.reps), (111, "1".to_string(), 3));
+        assert_eq!(histogram(&input, &rule, Semantics::Set)[&19].count, 9);
+        Ok(())
+    }
+}
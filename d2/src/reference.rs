@@ -0,0 +1,89 @@
+use aoc::check::{Mismatch, differential, without_one};
+use std::collections::BTreeSet;
+use std::ops::{Range, RangeInclusive};
+
+use crate::{IdRule, Semantics, T, generator, parse};
+
+/// Scans every ID in the ranges, comparing its digit blocks as a string. With set semantics,
+/// the IDs are collected into a set first.
+pub fn task1(input: &[(T, T)], n_reps: RangeInclusive<usize>, semantics: Semantics) -> T {
+    let ids = input.iter().flat_map(|&(start, end)| start..=end);
+    let ids: Box<dyn Iterator<Item = T>> = match semantics {
+        Semantics::Set => Box::new(ids.collect::<BTreeSet<_>>().into_iter()),
+        Semantics::Multiset => Box::new(ids),
+    };
+    ids.filter(|&n| {
+        let s = n.to_string().into_bytes();
+        let l = s.len();
+        n_reps.clone().any(|n_rep| {
+            n_rep > 0 && l % n_rep == 0 && {
+                let step = l / n_rep;
+                s.chunks(step).skip(1).all(|c| c == &s[..step])
+            }
+        })
+    })
+    .sum()
+}
+
+fn render(input: &[(T, T)]) -> String {
+    input
+        .iter()
+        .map(|(start, end)| format!("{start}-{end}"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn shrink(input: &[(T, T)]) -> Vec<Vec<(T, T)>> {
+    let mut candidates = without_one(input, 1);
+    for (i, &(start, end)) in input.iter().enumerate() {
+        if start < end {
+            let mid = start + (end - start) / 2;
+            for narrower in [
+                (start, mid),
+                (mid + 1, end),
+                (start + 1, end),
+                (start, end - 1),
+            ] {
+                let mut c = input.to_vec();
+                c[i] = narrower;
+                candidates.push(c);
+            }
+        }
+    }
+    candidates
+}
+
+/// Compares both parts with the scan on inputs of up to 5 ranges, under `semantics`.
+pub fn check_with(seeds: Range<u64>, semantics: Semantics) -> Option<(u8, Mismatch<String, T>)> {
+    let generate = |seed| parse(&generator::generate(seed, 1 + seed as usize % 5)).unwrap();
+    (1..).zip([2..=2, 2..=6]).find_map(|(part, n_reps)| {
+        let m = differential(
+            seeds.clone(),
+            generate,
+            |i: &Vec<_>| shrink(i),
+            |i| crate::task1(i, &IdRule::repeated(n_reps.clone()), semantics).unwrap(),
+            |i| task1(i, n_reps.clone(), semantics),
+        )?;
+        Some((part, m.map_input(|i| render(i))))
+    })
+}
+
+/// Compares both parts, as the puzzle counts them, with the scan.
+pub fn check(seeds: Range<u64>) -> Option<(u8, Mismatch<String, T>)> {
+    check_with(seeds, Semantics::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference() {
+        if let Some((part, m)) = check(0..200) {
+            panic!("part {part}: {m}");
+        }
+        if let Some((part, m)) = check_with(0..100, Semantics::Multiset) {
+            panic!("part {part}, multiset: {m}");
+        }
+    }
+}
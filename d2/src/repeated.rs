@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, RangeInclusive, Sub};
+
+use crate::T;
+
+pub(crate) fn digits(base: u32, n: T) -> u32 {
+    n.checked_ilog(base as T).map_or(1, |d| d + 1)
+}
+
+/// The number that turns a `period`-digit block into `len` digits of it, e.g. `1001001` for
+/// three blocks of three decimal digits.
+fn multiplier(base: u32, len: u32, period: u32) -> u128 {
+    let base = base as u128;
+    (base.pow(len) - 1) / (base.pow(period) - 1)
+}
+
+/// Block lengths that make `len`-digit IDs out of a block repeated any of `n_reps` times,
+/// shortest first.
+fn periods(len: u32, n_reps: &RangeInclusive<usize>) -> Vec<u32> {
+    (1..=len)
+        .filter(|&p| len.is_multiple_of(p) && n_reps.contains(&((len / p) as usize)))
+        .collect()
+}
+
+/// The `len`-digit part of `start..=end`.
+pub(crate) fn bounds(base: u32, start: T, end: T, len: u32) -> (u128, u128) {
+    let base = base as u128;
+    let lo = (start as u128).max(base.pow(len - 1));
+    let hi = (end as u128).min(base.pow(len) - 1);
+    (lo, hi)
+}
+
+/// How many IDs there are and what they add up to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub count: u128,
+    pub sum: u128,
+}
+
+impl Add for Totals {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            count: self.count + other.count,
+            sum: self.sum + other.sum,
+        }
+    }
+}
+
+impl AddAssign for Totals {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Totals {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            count: self.count - other.count,
+            sum: self.sum - other.sum,
+        }
+    }
+}
+
+impl Sum for Totals {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// The `len`-digit IDs in `lo..=hi` that repeat a `period`-digit block, as the block sum times
+/// the multiplier.
+fn repetitions(base: u32, lo: u128, hi: u128, len: u32, period: u32) -> Totals {
+    let m = multiplier(base, len, period);
+    let (first, last) = (lo.div_ceil(m), hi / m);
+    if first > last {
+        return Totals::default();
+    }
+    let count = last - first + 1;
+    // One of the factors is even; halving it first keeps 64-bit blocks within a `u128`.
+    let blocks = if count % 2 == 0 {
+        count / 2 * (first + last)
+    } else {
+        count * ((first + last) / 2)
+    };
+    Totals {
+        count,
+        sum: blocks * m,
+    }
+}
+
+/// Counts and sums the IDs that [`repeated_ids`] yields by how many times their shortest
+/// allowed block repeats, in `O(digits²)`.
+///
+/// For each length, the IDs whose shortest block has a given length are those repeating that
+/// block, less those whose shortest block is shorter and divides it. An ID counts if its
+/// shortest block divides any of the allowed ones.
+pub(crate) fn repeated_histogram(
+    base: u32,
+    start: T,
+    end: T,
+    n_reps: &RangeInclusive<usize>,
+) -> BTreeMap<usize, Totals> {
+    let mut histogram = BTreeMap::new();
+    for len in digits(base, start)..=digits(base, end) {
+        let (lo, hi) = bounds(base, start, end, len);
+        let periods = periods(len, n_reps);
+        let divisors: Vec<_> = (1..=len).filter(|&d| len.is_multiple_of(d)).collect();
+        let mut shortest: Vec<Totals> = Vec::with_capacity(divisors.len());
+        for &d in &divisors {
+            let shorter = (divisors.iter().zip(&shortest))
+                .filter(|&(&s, _)| d.is_multiple_of(s))
+                .map(|(_, &t)| t)
+                .sum();
+            shortest.push(repetitions(base, lo, hi, len, d) - shorter);
+        }
+        for (d, totals) in divisors.into_iter().zip(shortest) {
+            if let Some(p) = periods.iter().find(|p| p.is_multiple_of(d))
+                && totals.count > 0
+            {
+                *histogram.entry((len / p) as usize).or_default() += totals;
+            }
+        }
+    }
+    histogram
+}
+
+pub(crate) fn repeated_totals(
+    base: u32,
+    start: T,
+    end: T,
+    n_reps: &RangeInclusive<usize>,
+) -> Totals {
+    repeated_histogram(base, start, end, n_reps)
+        .into_values()
+        .sum()
+}
+
+/// The IDs in `start..=end` made of a block of `base` digits repeated any of `n_reps` times,
+/// by length.
+///
+/// For each length and block length, only the blocks whose repetitions fall in the range are
+/// visited. An ID made of several allowed blocks, like `222222`, is yielded for the shortest,
+/// and skipped when its repetitions of the longer blocks come up.
+pub(crate) fn repeated_ids(
+    base: u32,
+    start: T,
+    end: T,
+    n_reps: RangeInclusive<usize>,
+) -> impl Iterator<Item = T> {
+    (digits(base, start)..=digits(base, end)).flat_map(move |len| {
+        let periods = periods(len, &n_reps);
+        let (lo, hi) = bounds(base, start, end, len);
+        (0..periods.len()).flat_map(move |i| {
+            let m = multiplier(base, len, periods[i]);
+            let shorter: Vec<_> = (periods[..i].iter())
+                .map(|&p| multiplier(base, len, p))
+                .collect();
+            (lo.div_ceil(m)..=hi / m)
+                .map(move |block| block * m)
+                .filter(move |id| shorter.iter().all(|s| id % s != 0))
+                .map(|id| id as T)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multiplier() {
+        assert_eq!(multiplier(10, 9, 3), 1001001);
+        assert_eq!(multiplier(10, 6, 1), 111111);
+        assert_eq!(multiplier(10, 4, 4), 1);
+        assert_eq!(multiplier(16, 4, 2), 0x101);
+        assert_eq!(periods(12, &(2..=6)), [2, 3, 4, 6]);
+    }
+
+    #[test]
+    fn test_repeated_ids() {
+        let ids: Vec<_> = repeated_ids(10, 222220, 222224, 2..=6).collect();
+        assert_eq!(ids, [222222]);
+        let ids: Vec<_> = repeated_ids(10, 95, 1012, 2..=6).collect();
+        assert_eq!(ids, [99, 111, 222, 333, 444, 555, 666, 777, 888, 999, 1010]);
+        let ids: Vec<_> = repeated_ids(10, 95, 1012, 2..=2).collect();
+        assert_eq!(ids, [99, 1010]);
+        assert_eq!(repeated_ids(10, 1, 9, 1..=1).count(), 9);
+        assert_eq!(repeated_ids(10, 20, 10, 2..=2).count(), 0);
+    }
+
+    #[test]
+    fn test_wide_range() {
+        // Every even length up to 12 digits: 9 + 90 + ... + 900000 blocks.
+        assert_eq!(repeated_ids(10, 1, 10u64.pow(13), 2..=2).count(), 999999);
+        let max: Vec<_> = repeated_ids(10, T::MAX - 2 * 10u64.pow(9), T::MAX, 2..=20).collect();
+        assert_eq!(max, [18446744071844674407]);
+    }
+
+    #[test]
+    fn test_totals() {
+        let ranges = [
+            (1, 99_999),
+            (95, 1012),
+            (222220, 222224),
+            (1188511880, 1188511890),
+        ];
+        for n_reps in [1..=1, 2..=2, 2..=6, 3..=4, 4..=4, 0..=20] {
+            for (start, end) in ranges {
+                let ids: Vec<_> = repeated_ids(10, start, end, n_reps.clone()).collect();
+                let expected = Totals {
+                    count: ids.len() as u128,
+                    sum: ids.iter().map(|&id| id as u128).sum(),
+                };
+                let totals = repeated_totals(10, start, end, &n_reps);
+                assert_eq!(totals, expected, "{start}-{end} {n_reps:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_histogram() {
+        let histogram = repeated_histogram(10, 1, 999_999, &(2..=6));
+        let counts: Vec<_> = histogram.iter().map(|(&reps, t)| (reps, t.count)).collect();
+        // `111111` is six 1s rather than three 11s or two 111s.
+        assert_eq!(
+            counts,
+            [(2, 9 + 81 + 891), (3, 9 + 81), (4, 9), (5, 9), (6, 9)]
+        );
+    }
+
+    #[test]
+    fn test_totals_wide() {
+        let all = repeated_totals(10, 0, T::MAX, &(1..=1));
+        assert_eq!(all.count, T::MAX as u128);
+        assert_eq!(all.sum, T::MAX as u128 * (T::MAX as u128 + 1) / 2);
+        let pairs = repeated_totals(10, 1, 10u64.pow(13), &(2..=2));
+        let ids = repeated_ids(10, 1, 10u64.pow(13), 2..=2);
+        assert_eq!(pairs.sum, ids.map(|id| id as u128).sum());
+        assert_eq!(pairs.count, 999999);
+    }
+}
@@ -0,0 +1,274 @@
+use anyhow::{Result, ensure};
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use crate::repeated::{bounds, digits, repeated_histogram, repeated_ids, repeated_totals};
+use crate::{T, Totals};
+
+pub(crate) fn check_base(base: u32) -> Result<()> {
+    ensure!(
+        (2..=36).contains(&base),
+        "base {base} is not between 2 and 36"
+    );
+    Ok(())
+}
+
+/// `id`'s digits in `base`, most significant first.
+pub(crate) fn to_digits(base: u32, mut id: T) -> Vec<u8> {
+    let mut digits = vec![];
+    loop {
+        digits.push((id % base as T) as u8);
+        id /= base as T;
+        if id == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    digits
+}
+
+/// A shape of digits that makes an ID invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pattern {
+    /// The whole ID is one block repeated any of these many times.
+    Repeated(RangeInclusive<usize>),
+    /// The ID reads the same backwards.
+    Palindrome,
+    /// Some block appears at least this many times back to back, like `123` twice in `91231239`.
+    Run(usize),
+}
+
+/// Which IDs are invalid: those whose digits in `base` match `pattern`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdRule {
+    base: u32,
+    pattern: Pattern,
+}
+
+impl IdRule {
+    pub fn new(base: u32, pattern: Pattern) -> Result<Self> {
+        check_base(base)?;
+        Ok(Self { base, pattern })
+    }
+
+    /// Decimal IDs made of a block repeated any of `reps` times.
+    pub fn repeated(reps: RangeInclusive<usize>) -> Self {
+        Self {
+            base: 10,
+            pattern: Pattern::Repeated(reps),
+        }
+    }
+
+    /// Decimal IDs made of a block repeated exactly `n` times.
+    pub fn exactly(n: usize) -> Self {
+        Self::repeated(n..=n)
+    }
+
+    /// Decimal IDs made of a block repeated `n` times or more.
+    pub fn at_least(n: usize) -> Self {
+        Self::repeated(n..=usize::MAX)
+    }
+
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    pub fn pattern(&self) -> &Pattern {
+        &self.pattern
+    }
+
+    pub fn matches(&self, id: T) -> bool {
+        self.explain(id).is_some()
+    }
+
+    /// Why `id` matches: the block, in the rule's base, and how many times it appears.
+    ///
+    /// That is the shortest allowed block for repetitions, the first of the shortest blocks
+    /// for runs, and the whole ID once for palindromes. IDs start at 1, so 0 never matches.
+    pub fn explain(&self, id: T) -> Option<(String, usize)> {
+        if id == 0 {
+            return None;
+        }
+        let d = to_digits(self.base, id);
+        let render = |block: &[u8]| {
+            (block.iter())
+                .map(|&digit| char::from_digit(digit as u32, 36).unwrap())
+                .collect()
+        };
+        match &self.pattern {
+            Pattern::Repeated(reps) => (1..=d.len())
+                .filter(|&p| d.len().is_multiple_of(p) && reps.contains(&(d.len() / p)))
+                .find(|&p| d.chunks(p).all(|c| c == &d[..p]))
+                .map(|p| (render(&d[..p]), d.len() / p)),
+            Pattern::Palindrome => d.iter().eq(d.iter().rev()).then(|| (render(&d), 1)),
+            Pattern::Run(k) if *k <= 1 => Some((render(&d), 1)),
+            Pattern::Run(k) => (1..=d.len() / k).find_map(|p| {
+                let i = (0..=d.len() - p * k)
+                    .find(|&i| d[i..i + p * k].chunks(p).all(|c| c == &d[i..i + p]))?;
+                let reps = d[i..]
+                    .chunks_exact(p)
+                    .take_while(|c| c == &&d[i..i + p])
+                    .count();
+                Some((render(&d[i..i + p]), reps))
+            }),
+        }
+    }
+
+    /// The matching IDs in `start..=end`, by length.
+    ///
+    /// Repetitions and palindromes are built from their blocks or halves, so only matching IDs
+    /// are visited; runs are found by checking every ID in the range, which takes time
+    /// proportional to its width.
+    pub fn ids(&self, start: T, end: T) -> Box<dyn Iterator<Item = T> + '_> {
+        match &self.pattern {
+            Pattern::Repeated(reps) => Box::new(repeated_ids(self.base, start, end, reps.clone())),
+            Pattern::Palindrome => Box::new(self.palindromes(start, end)),
+            Pattern::Run(_) => Box::new((start..=end).filter(|&id| self.matches(id))),
+        }
+    }
+
+    /// Counts and sums [`IdRule::ids`], in `O(digits²)` for repetitions but in time proportional
+    /// to the range's width for runs.
+    pub fn totals(&self, start: T, end: T) -> Totals {
+        match &self.pattern {
+            Pattern::Repeated(reps) => repeated_totals(self.base, start, end, reps),
+            _ => self.ids(start, end).map(Totals::from).sum(),
+        }
+    }
+
+    /// Counts and sums [`IdRule::ids`] by the repetitions [`IdRule::explain`] gives them, in
+    /// `O(digits²)` for repetitions and, like [`IdRule::totals`], in time proportional to the
+    /// range's width for runs.
+    pub fn histogram(&self, start: T, end: T) -> BTreeMap<usize, Totals> {
+        if let Pattern::Repeated(reps) = &self.pattern {
+            return repeated_histogram(self.base, start, end, reps);
+        }
+        let mut histogram = BTreeMap::new();
+        for id in self.ids(start, end) {
+            let (_, reps) = self.explain(id).expect("only matching IDs are listed");
+            *histogram.entry(reps).or_insert_with(Totals::default) += Totals::from(id);
+        }
+        histogram
+    }
+
+    /// Each palindrome is its first half, mirrored; the halves of those in the range are
+    /// consecutive.
+    fn palindromes(&self, start: T, end: T) -> impl Iterator<Item = T> + '_ {
+        let base = self.base as u128;
+        (digits(self.base, start)..=digits(self.base, end)).flat_map(move |len| {
+            let (lo, hi) = bounds(self.base, start, end, len);
+            let rest = len / 2;
+            let mirror = move |half: u128| {
+                let (mut id, mut tail) = (half, half / base.pow(len % 2));
+                for _ in 0..rest {
+                    id = id * base + tail % base;
+                    tail /= base;
+                }
+                id
+            };
+            let (mut first, mut last) = (lo / base.pow(rest), hi / base.pow(rest));
+            if mirror(first) < lo {
+                first += 1;
+            }
+            if mirror(last) > hi {
+                last -= 1;
+            }
+            (first..=last).map(move |half| mirror(half) as T)
+        })
+    }
+}
+
+impl From<T> for Totals {
+    fn from(id: T) -> Self {
+        Self {
+            count: 1,
+            sum: id as u128,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(IdRule::new(36, Pattern::Palindrome).is_ok());
+        let e = IdRule::new(37, Pattern::Palindrome).unwrap_err();
+        assert_eq!(e.to_string(), "base 37 is not between 2 and 36");
+        assert!(IdRule::new(1, Pattern::Run(2)).is_err());
+    }
+
+    #[test]
+    fn test_matches() -> Result<()> {
+        let hex = IdRule::new(16, Pattern::Repeated(2..=2))?;
+        assert!(hex.matches(0xabab) && !hex.matches(0xabac) && !hex.matches(0xaaa));
+        let b36 = IdRule::new(36, Pattern::Repeated(3..=usize::MAX))?;
+        assert!(b36.matches(36 * 36 * 35 + 36 * 35 + 35)); // ZZZ
+        assert!(IdRule::new(2, Pattern::Palindrome)?.matches(0b1001));
+        let run = IdRule::new(10, Pattern::Run(3))?;
+        assert!(run.matches(91231231239) && run.matches(5550) && !run.matches(12312));
+        assert!(IdRule::at_least(2).matches(1111111) && !IdRule::exactly(2).matches(11111));
+        Ok(())
+    }
+
+    #[test]
+    fn test_explain() -> Result<()> {
+        let explain = |rule: &IdRule, id| rule.explain(id).map(|(b, r)| (b.to_string(), r));
+        assert_eq!(
+            explain(&IdRule::repeated(2..=6), 222222),
+            Some(("2".into(), 6))
+        );
+        assert_eq!(
+            explain(&IdRule::repeated(2..=3), 222222),
+            Some(("22".into(), 3))
+        );
+        assert_eq!(explain(&IdRule::exactly(2), 123), None);
+        assert_eq!(explain(&IdRule::repeated(1..=1), 0), None);
+        let hex = IdRule::new(16, Pattern::Repeated(2..=2))?;
+        assert_eq!(explain(&hex, 0xabab), Some(("ab".into(), 2)));
+        let run = IdRule::new(10, Pattern::Run(2))?;
+        assert_eq!(explain(&run, 91231231239), Some(("123".into(), 3)));
+        assert_eq!(explain(&run, 1001), Some(("0".into(), 2)));
+        let palindrome = IdRule::new(36, Pattern::Palindrome)?;
+        assert_eq!(
+            explain(&palindrome, 36 * 36 * 10 + 35 * 36 + 10),
+            Some(("aza".into(), 1))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_ids() -> Result<()> {
+        let patterns = [
+            Pattern::Repeated(2..=2),
+            Pattern::Repeated(2..=usize::MAX),
+            Pattern::Palindrome,
+            Pattern::Run(2),
+        ];
+        for base in [2, 3, 10, 16, 36] {
+            for pattern in patterns.clone() {
+                let rule = IdRule::new(base, pattern)?;
+                for (start, end) in [(0, 2000), (1295, 1297), (5000, 4000), (46650, 47000)] {
+                    let expected: Vec<_> = (start..=end).filter(|&id| rule.matches(id)).collect();
+                    let mut ids: Vec<_> = rule.ids(start, end).collect();
+                    ids.sort_unstable();
+                    assert_eq!(ids, expected, "{rule:?} {start}-{end}");
+                    let totals = expected.iter().map(|&id| Totals::from(id)).sum();
+                    assert_eq!(rule.totals(start, end), totals, "{rule:?} {start}-{end}");
+                    let histogram = rule.histogram(start, end);
+                    assert_eq!(histogram.into_values().sum::<Totals>(), totals);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_wide_palindromes() -> Result<()> {
+        let rule = IdRule::new(10, Pattern::Palindrome)?;
+        assert_eq!(rule.ids(T::MAX - 10u64.pow(10), T::MAX).count(), 1);
+        assert_eq!(rule.ids(1, 10u64.pow(10)).count(), 199998);
+        Ok(())
+    }
+}
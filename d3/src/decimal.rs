@@ -0,0 +1,111 @@
+use std::fmt::{self, Display};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+
+/// A non-negative decimal number of any size.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    /// Least significant first, without leading zeros, so 0 has none.
+    digits: Vec<u8>,
+}
+
+impl Decimal {
+    /// The number written with `digits`, most significant first.
+    pub fn from_digits(digits: &[u8]) -> Self {
+        let mut digits: Vec<_> = digits.iter().rev().copied().collect();
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Self { digits }
+    }
+
+    /// The digits, most significant first; 0 is `[0]`.
+    pub fn digits(&self) -> Vec<u8> {
+        match self.digits.is_empty() {
+            true => vec![0],
+            false => self.digits.iter().rev().copied().collect(),
+        }
+    }
+
+    /// The number, if it fits in a `u64`.
+    pub fn to_u64(&self) -> Option<u64> {
+        (self.digits.iter().rev()).try_fold(0u64, |n, &d| n.checked_mul(10)?.checked_add(d as u64))
+    }
+}
+
+impl From<u64> for Decimal {
+    fn from(mut n: u64) -> Self {
+        let mut digits = vec![];
+        while n > 0 {
+            digits.push((n % 10) as u8);
+            n /= 10;
+        }
+        Self { digits }
+    }
+}
+
+impl AddAssign<&Decimal> for Decimal {
+    fn add_assign(&mut self, other: &Decimal) {
+        let len = self.digits.len().max(other.digits.len());
+        self.digits.resize(len, 0);
+        let mut carry = 0;
+        for (i, d) in self.digits.iter_mut().enumerate() {
+            let sum = *d + other.digits.get(i).copied().unwrap_or(0) + carry;
+            (*d, carry) = (sum % 10, sum / 10);
+        }
+        if carry > 0 {
+            self.digits.push(carry);
+        }
+    }
+}
+
+impl Add<&Decimal> for Decimal {
+    type Output = Decimal;
+
+    fn add(mut self, other: &Decimal) -> Decimal {
+        self += other;
+        self
+    }
+}
+
+impl<'a> Sum<&'a Decimal> for Decimal {
+    fn sum<I: Iterator<Item = &'a Decimal>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits: String = self
+            .digits()
+            .iter()
+            .map(|&d| char::from(b'0' + d))
+            .collect();
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal() {
+        let n = Decimal::from_digits(&[0, 0, 9, 9]);
+        assert_eq!((n.to_string(), n.digits()), ("99".to_string(), vec![9, 9]));
+        assert_eq!(Decimal::from_digits(&[0]).to_string(), "0");
+        assert_eq!(Decimal::default().digits(), [0]);
+        assert_eq!((n + &Decimal::from(1)).to_string(), "100");
+
+        let max = Decimal::from(u64::MAX);
+        assert_eq!(max.to_u64(), Some(u64::MAX));
+        let over = max + &Decimal::from(1);
+        assert_eq!(
+            (over.to_string(), over.to_u64()),
+            ("18446744073709551616".into(), None)
+        );
+        let nines = Decimal::from_digits(&[9; 30]);
+        let sum: Decimal = [nines.clone(), nines].iter().sum();
+        assert_eq!(sum.to_string(), format!("1{}8", "9".repeat(29)));
+    }
+}
@@ -0,0 +1,52 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// `size` banks of 100 batteries rated 1 to 9, one bank per line.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    (0..size.max(1))
+        .map(|_| {
+            (0..100)
+                .map(|_| char::from(b'0' + rng.random_range(1..=9)))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, task1};
+    use anyhow::Result;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = parse(&generate(1, 50))?;
+        assert_eq!(input.len(), 50);
+        assert!(input.iter().all(|bank| bank.len() == 100));
+        assert!(input.iter().flatten().all(|b| (1..=9).contains(b)));
+        let pairs: u64 = (input.iter())
+            .map(|bank| {
+                let pairs = (0..100).flat_map(|i| (i + 1..100).map(move |j| (i, j)));
+                pairs
+                    .map(|(i, j)| 10 * bank[i] as u64 + bank[j] as u64)
+                    .max()
+                    .unwrap()
+            })
+            .sum();
+        assert_eq!(task1(&input, 2)?, pairs);
+        assert!(task1(&input, 12)? > pairs);
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_small() -> Result<()> {
+        let input = parse(&generate(0, 2))?;
+        assert!(input[0].starts_with(&[6, 7, 7, 5, 5, 7, 6]));
+        // The first bank has twelve 9s, the second eleven with a 6 after them.
+        assert_eq!(task1(&input, 2)?, 99 + 99);
+        assert_eq!(task1(&input, 12)?, 999_999_999_999 + 999_999_999_996);
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt::Display;
+
+use anyhow::{Context, Result, ensure};
+use aoc::Solution;
+use winnow::ascii::dec_uint;
+use winnow::combinator::{alt, cut_err, eof, peek, repeat_till, separated};
+use winnow::error::{StrContext, StrContextValue::Description};
+use winnow::{Parser, token::take};
+
+mod decimal;
+pub mod generator;
+mod options;
+pub mod reference;
+pub use decimal::Decimal;
+pub use options::{Goal, Options};
+
+fn parse_(input: &mut &str) -> winnow::ModalResult<Vec<Vec<u8>>> {
+    let digit = take(1usize)
+        .and_then(dec_uint::<_, u8, _>)
+        .context(StrContext::Expected(Description("digit")));
+    // Reading digits up to the end of the line reports a stray character as a bad digit.
+    let line = repeat_till(1.., digit, peek(alt(('\n', eof.value('\n')))))
+        .map(|(v, _): (Vec<u8>, char)| v);
+    separated(1.., cut_err(line), '\n').parse_next(input)
+}
+
+/// Parses one bank of single-digit batteries per line.
+pub fn parse(input: &str) -> Result<Vec<Vec<u8>>> {
+    aoc::parse(parse_, input)
+}
+
+/// The batteries turned on in a bank, and the joltage they produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    pub value: Decimal,
+    /// Positions in the bank, in increasing order.
+    pub indices: Vec<usize>,
+}
+
+/// The `n` batteries of `bank` that best meet `options`, preferring earlier ones on ties;
+/// `None` if no `n` of them are allowed together.
+///
+/// # Panics
+///
+/// If a [`Goal::Weighted`] doesn't have `n` weights.
+pub fn select(bank: &[u8], n: usize, options: &Options) -> Option<Selection> {
+    let indices = match options.is_default() {
+        true => stack(bank, n)?,
+        false => options::select(bank, n, options)?,
+    };
+    let digits: Vec<_> = indices.iter().map(|&i| bank[i]).collect();
+    let value = Decimal::from_digits(&digits);
+    Some(Selection { value, indices })
+}
+
+/// The positions of the `n` batteries that form the largest number.
+///
+/// A monotonic stack of positions: a battery pushes out the weaker ones before it while there
+/// are batteries left to skip, so each is pushed and popped at most once.
+fn stack(bank: &[u8], n: usize) -> Option<Vec<usize>> {
+    let mut skips = bank.len().checked_sub(n)?;
+    let mut indices: Vec<usize> = Vec::with_capacity(bank.len());
+    for (i, &battery) in bank.iter().enumerate() {
+        while skips > 0
+            && let Some(&last) = indices.last()
+            && bank[last] < battery
+        {
+            indices.pop();
+            skips -= 1;
+        }
+        indices.push(i);
+    }
+    indices.truncate(n);
+    Some(indices)
+}
+
+/// The selection of `n` batteries in each bank.
+pub fn selections(input: &[Vec<u8>], n: usize, options: &Options) -> Result<Vec<Selection>> {
+    if let Goal::Weighted(weights) = &options.goal {
+        ensure!(
+            weights.len() == n,
+            "{} weights for {n} batteries",
+            weights.len()
+        );
+    }
+    (1..)
+        .zip(input)
+        .map(|(line, bank)| {
+            ensure!(
+                bank.len() >= n,
+                "bank {line} has {} batteries, fewer than {n}",
+                bank.len()
+            );
+            select(bank, n, options)
+                .with_context(|| format!("bank {line} has no {n} batteries allowed together"))
+        })
+        .collect()
+}
+
+/// Sums, over all banks, the largest number formed by turning on `n` batteries in order,
+/// failing if a number or the sum doesn't fit in a `u64`.
+pub fn task1(input: &[Vec<u8>], n: usize) -> Result<u64> {
+    (1..)
+        .zip(selections(input, n, &Options::default())?)
+        .try_fold(0u64, |sum, (line, s)| {
+            let value = (s.value.to_u64())
+                .with_context(|| format!("bank {line}'s {} doesn't fit in a u64", s.value))?;
+            sum.checked_add(value)
+                .context("the sum doesn't fit in a u64")
+        })
+}
+
+/// Like [`task1`], but exact for any `n`.
+pub fn task1_exact(input: &[Vec<u8>], n: usize) -> Result<Decimal> {
+    Ok(selections(input, n, &Options::default())?
+        .iter()
+        .map(|s| &s.value)
+        .sum())
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input<'a> = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<u8>>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display> {
+        task1(input, 2)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display> {
+        task1(input, 12)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r"987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+    #[test]
+    fn test_task1() -> Result<()> {
+        let input = parse(INPUT)?;
+        assert_eq!(task1(&input, 2)?, 357);
+        Ok(())
+    }
+
+    #[test]
+    fn test_task2() -> Result<()> {
+        let input = parse(INPUT)?;
+        assert_eq!(task1(&input, 12)?, 3121910778619);
+        Ok(())
+    }
+
+    #[test]
+    fn test_select() -> Result<()> {
+        let input = parse(INPUT)?;
+        let selections = selections(&input, 2, &Options::default())?;
+        let indices: Vec<_> = selections.iter().map(|s| &s.indices[..]).collect();
+        assert_eq!(indices, [[0, 1], [0, 14], [13, 14], [6, 11]]);
+        let default = Options::default();
+        assert_eq!(select(&[3, 3, 3], 2, &default).unwrap().indices, [0, 1]);
+        assert_eq!(
+            select(&[1, 2], 0, &default).unwrap().value,
+            Decimal::default()
+        );
+        assert_eq!(select(&[1, 2], 3, &default), None);
+        let e = task1(&input, 16).unwrap_err();
+        assert_eq!(e.to_string(), "bank 1 has 15 batteries, fewer than 16");
+        Ok(())
+    }
+
+    #[test]
+    fn test_options() -> Result<()> {
+        let input = parse(INPUT)?;
+        let values = |options: &Options| -> Result<Vec<String>> {
+            let selections = selections(&input, 2, options)?;
+            Ok(selections.iter().map(|s| s.value.to_string()).collect())
+        };
+        let min = Options {
+            goal: Goal::Min,
+            ..Options::default()
+        };
+        assert_eq!(values(&min)?, ["11", "11", "22", "11"]);
+        let spaced = Options {
+            min_gap: 2,
+            forbidden: [0].into(),
+            ..Options::default()
+        };
+        assert_eq!(values(&spaced)?, ["86", "19", "48", "92"]);
+        let weighted = Options {
+            goal: Goal::Weighted(vec![1, 10]),
+            ..Options::default()
+        };
+        assert_eq!(values(&weighted)?, ["98", "89", "78", "89"]);
+
+        let e = selections(&input, 3, &weighted).unwrap_err();
+        assert_eq!(e.to_string(), "2 weights for 3 batteries");
+        let sparse = Options {
+            min_gap: 8,
+            ..Options::default()
+        };
+        let e = selections(&input, 3, &sparse).unwrap_err();
+        assert_eq!(e.to_string(), "bank 1 has no 3 batteries allowed together");
+        Ok(())
+    }
+
+    #[test]
+    fn test_overflow() -> Result<()> {
+        let input = parse(INPUT)?;
+        assert_eq!(task1_exact(&input, 12)?.to_string(), "3121910778619");
+        assert_eq!(task1(&input, 15)?, 2851181577568619);
+        assert_eq!(task1_exact(&input, 15)?.to_u64(), Some(2851181577568619));
+
+        let nines = parse(&["9".repeat(19), "9".repeat(19)].join("\n"))?;
+        let e = task1(&nines, 19).unwrap_err();
+        assert_eq!(e.to_string(), "the sum doesn't fit in a u64");
+        assert_eq!(
+            task1_exact(&nines, 19)?.to_string(),
+            format!("1{}8", "9".repeat(18))
+        );
+
+        let long = parse(&"12".repeat(50))?;
+        let e = task1(&long, 40).unwrap_err();
+        let twos = "2".repeat(40);
+        assert_eq!(
+            e.to_string(),
+            format!("bank 1's {twos} doesn't fit in a u64")
+        );
+        assert_eq!(task1_exact(&long, 40)?.to_string(), twos);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse("987\n81a").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(e.label, "expected digit, found `a`");
+        let e = parse("987\n\n81").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.label, "expected digit, found newline");
+    }
+
+    #[test]
+    fn test_main() -> Result<()> {
+        aoc::run::<Day3>()
+    }
+}
@@ -0,0 +1,167 @@
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
+
+/// What a selection of batteries is best at.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Goal {
+    /// The largest number, read in order.
+    #[default]
+    Max,
+    /// The smallest number, read in order.
+    Min,
+    /// The largest sum of each chosen battery times the weight of its slot, so the weights
+    /// `[10, 1]` give the same two batteries as `Max`.
+    Weighted(Vec<i64>),
+}
+
+/// How to choose the batteries in a bank.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    pub goal: Goal,
+    /// How far apart chosen positions must be; 0 and 1 both allow neighbours.
+    pub min_gap: usize,
+    /// Positions that can't be chosen.
+    pub forbidden: BTreeSet<usize>,
+}
+
+impl Options {
+    /// Whether the monotonic stack in [`crate::select`] applies.
+    pub(crate) fn is_default(&self) -> bool {
+        self.goal == Goal::Max && self.min_gap <= 1 && self.forbidden.is_empty()
+    }
+}
+
+/// The positions of `n` batteries that meet `options`, preferring earlier ones on ties, or
+/// `None` if there are no such `n`.
+///
+/// Picks one slot at a time, like [`crate::reference`], among the positions that leave
+/// room for the rest; weighted scores are maximised over all positions and slots instead.
+pub(crate) fn select(bank: &[u8], n: usize, options: &Options) -> Option<Vec<usize>> {
+    let len = bank.len();
+    let gap = options.min_gap.max(1);
+    let allowed = |i: usize| !options.forbidden.contains(&i);
+    // The most batteries that can be chosen from each position on.
+    let mut room = vec![0; len + gap];
+    for i in (0..len).rev() {
+        room[i] = room[i + 1].max(if allowed(i) { 1 + room[i + gap] } else { 0 });
+    }
+    if room[0] < n {
+        return None;
+    }
+
+    let key = |i: usize| match options.goal {
+        Goal::Min => -(bank[i] as i16),
+        _ => bank[i] as i16,
+    };
+    let weights = match &options.goal {
+        Goal::Max | Goal::Min => {
+            let mut indices = Vec::with_capacity(n);
+            let mut from = 0;
+            for left in (0..n).rev() {
+                let i = (from..len)
+                    .filter(|&i| allowed(i) && room[i + gap] >= left)
+                    .max_by_key(|&i| (key(i), Reverse(i)))?;
+                indices.push(i);
+                from = i + gap;
+            }
+            return Some(indices);
+        }
+        Goal::Weighted(weights) => weights,
+    };
+    assert_eq!(weights.len(), n, "one weight for each battery to choose");
+
+    // `best[i][k]`: the highest score from filling the last `k` slots from position `i` on.
+    let mut best = vec![vec![None; n + 1]; len + gap];
+    for row in &mut best {
+        row[0] = Some(0);
+    }
+    for i in (0..len).rev() {
+        for k in 1..=n {
+            let take = best[i + gap][k - 1]
+                .filter(|_| allowed(i))
+                .map(|rest: i64| weights[n - k] * bank[i] as i64 + rest);
+            best[i][k] = take.max(best[i + 1][k]);
+        }
+    }
+    let mut indices = Vec::with_capacity(n);
+    let mut i = 0;
+    for k in (1..=n).rev() {
+        while best[i][k] != best[i + gap][k - 1].map(|rest| weights[n - k] * bank[i] as i64 + rest)
+            || !allowed(i)
+        {
+            i += 1;
+        }
+        indices.push(i);
+        i += gap;
+    }
+    Some(indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+
+    /// Every way to choose `n` positions that meets `options`, with the digits they form.
+    fn all(bank: &[u8], n: usize, options: &Options) -> Vec<(Vec<usize>, Vec<u8>)> {
+        let gap = options.min_gap.max(1);
+        let mut found = vec![];
+        for mask in 0u32..1 << bank.len() {
+            let indices: Vec<_> = (0..bank.len()).filter(|i| mask & 1 << i != 0).collect();
+            let spaced = indices.windows(2).all(|w| w[1] - w[0] >= gap);
+            if indices.len() == n
+                && spaced
+                && indices.iter().all(|i| !options.forbidden.contains(i))
+            {
+                let digits = indices.iter().map(|&i| bank[i]).collect();
+                found.push((indices, digits));
+            }
+        }
+        found
+    }
+
+    #[test]
+    fn test_brute_force() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for _ in 0..300 {
+            let len = rng.random_range(0..12);
+            let bank: Vec<u8> = (0..len).map(|_| rng.random_range(0..10)).collect();
+            let n = rng.random_range(0..=len.min(5));
+            let forbidden = (0..len).filter(|_| rng.random_bool(0.2)).collect();
+            let weights = (0..n).map(|_| rng.random_range(-5..=5)).collect();
+            let min_gap = rng.random_range(0..3);
+            for goal in [Goal::Max, Goal::Min, Goal::Weighted(weights)] {
+                let options = Options {
+                    goal,
+                    min_gap,
+                    forbidden: BTreeSet::clone(&forbidden),
+                };
+                let found = all(&bank, n, &options);
+                let Some(indices) = select(&bank, n, &options) else {
+                    assert!(found.is_empty(), "{bank:?} {n} {options:?}");
+                    continue;
+                };
+                let digits: Vec<_> = indices.iter().map(|&i| bank[i]).collect();
+                assert!(found.iter().any(|(i, _)| *i == indices));
+                let score = |digits: &[u8]| match &options.goal {
+                    Goal::Weighted(w) => w.iter().zip(digits).map(|(&w, &d)| w * d as i64).sum(),
+                    _ => 0,
+                };
+                let best = match &options.goal {
+                    Goal::Max => found.iter().map(|(_, d)| d).max().unwrap(),
+                    Goal::Min => found.iter().map(|(_, d)| d).min().unwrap(),
+                    Goal::Weighted(_) => found
+                        .iter()
+                        .map(|(_, d)| d)
+                        .max_by_key(|d| score(d))
+                        .unwrap(),
+                };
+                assert_eq!(score(&digits), score(best), "{bank:?} {n} {options:?}");
+                if !matches!(options.goal, Goal::Weighted(_)) {
+                    assert_eq!(&digits, best, "{bank:?} {n} {options:?}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use aoc::check::{Mismatch, differential, without_one};
+use std::cmp::Reverse;
+use std::ops::Range;
+
+use crate::{generator, parse};
+
+/// Picks each battery as the leftmost largest one that leaves enough batteries after it,
+/// returning the positions.
+pub fn select(bank: &[u8], n: usize) -> Vec<usize> {
+    let mut indices = Vec::with_capacity(n);
+    let mut from = 0;
+    for left in (0..n).rev() {
+        let (i, _) = (bank[from..bank.len() - left].iter().enumerate())
+            .max_by_key(|&(i, &v)| (v, Reverse(i)))
+            .unwrap();
+        indices.push(from + i);
+        from += i + 1;
+    }
+    indices
+}
+
+fn render(input: &[Vec<u8>]) -> String {
+    (input.iter())
+        .map(|bank| {
+            bank.iter()
+                .map(|b| char::from(b'0' + b))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Each bank's positions, like `0,1 6,11`.
+fn positions(indices: impl Iterator<Item = Vec<usize>>) -> String {
+    (indices.map(|i| i.iter().map(usize::to_string).collect::<Vec<_>>().join(",")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Drops banks, or batteries from banks that have more than `n`.
+fn shrink(input: &[Vec<u8>], n: usize) -> Vec<Vec<Vec<u8>>> {
+    let mut candidates = without_one(input, 1);
+    for (i, bank) in input.iter().enumerate() {
+        for shorter in without_one(bank, n) {
+            let mut c = input.to_vec();
+            c[i] = shorter;
+            candidates.push(c);
+        }
+    }
+    candidates
+}
+
+/// Compares the positions both parts turn on with the window-by-window search, on inputs of
+/// up to 5 banks.
+pub fn check(seeds: Range<u64>) -> Option<(u8, Mismatch<String, String>)> {
+    let generate = |seed| parse(&generator::generate(seed, 1 + seed as usize % 5)).unwrap();
+    (1..).zip([2, 12]).find_map(|(part, n)| {
+        let m = differential(
+            seeds.clone(),
+            generate,
+            |i: &Vec<_>| shrink(i, n),
+            |i| {
+                positions(
+                    crate::selections(i, n, &Default::default())
+                        .unwrap()
+                        .into_iter()
+                        .map(|s| s.indices),
+                )
+            },
+            |i| positions(i.iter().map(|bank| select(bank, n))),
+        )?;
+        Some((part, m.map_input(|i| render(i))))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference() {
+        if let Some((part, m)) = check(0..500) {
+            panic!("part {part}: {m}");
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// A `size`×`size` grid where about two thirds of the tiles are rolls.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.random_bool(2.0 / 3.0) { '@' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Tile, parse, task1, task2};
+    use anyhow::Result;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = parse(&generate(1, 40))?;
+        assert_eq!((input.width(), input.height()), (40, 40));
+        let rolls = input.iter().filter(|&(_, &t)| t == Tile::Roll).count();
+        assert!((900..1200).contains(&rolls), "{rolls} rolls");
+        assert!(task1(&input).count() <= task2(input));
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_small() -> Result<()> {
+        let input = generate(0, 4);
+        assert_eq!(input, ".@.@\n.@..\n.@..\n@@.@");
+        // No roll has more than three neighbours, so all seven go at once.
+        let input = parse(&input)?;
+        assert_eq!((task1(&input).count(), task2(input)), (7, 7));
+        Ok(())
+    }
+}
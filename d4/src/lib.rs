@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use aoc::grid::Pos;
+use aoc::{Grid, Solution};
+
+pub mod generator;
+pub mod reference;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    Roll,
+}
+
+pub fn parse(input: &str) -> Result<Grid<Tile>> {
+    Grid::parse(input, |c| match c {
+        '.' => Some(Tile::Empty),
+        '@' => Some(Tile::Roll),
+        _ => None,
+    })
+}
+
+/// The rolls with fewer than four rolls among their eight neighbours.
+pub fn task1(input: &Grid<Tile>) -> impl Iterator<Item = Pos> {
+    input.positions().filter(move |&p| {
+        input[p] == Tile::Roll
+            && input
+                .neighbors8(p)
+                .filter(|&n| input[n] == Tile::Roll)
+                .count()
+                < 4
+    })
+}
+
+/// How many rolls can be removed by repeatedly taking the accessible ones.
+pub fn task2(mut input: Grid<Tile>) -> usize {
+    let mut count = 0;
+    loop {
+        let accessible_tiles: Vec<_> = task1(&input).collect();
+
+        if accessible_tiles.is_empty() {
+            break;
+        }
+        count += accessible_tiles.len();
+        for p in accessible_tiles {
+            input[p] = Tile::Empty;
+        }
+    }
+
+    count
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input<'a> = Grid<Tile>;
+
+    fn parse(input: &str) -> Result<Grid<Tile>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task1(input).count())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task2(input.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,106 @@
+use aoc::Grid;
+use aoc::check::{Mismatch, differential};
+use std::ops::Range;
+
+use crate::{Tile, generator, parse};
+
+type Solver = fn(&Grid<Tile>) -> usize;
+
+/// Whether the roll at `(x, y)` has fewer than four rolls around it, looking at each of the
+/// eight offsets in turn.
+fn accessible(input: &Grid<Tile>, x: usize, y: usize) -> bool {
+    let mut rolls = 0;
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            let (nx, ny) = (x as isize + dx, y as isize + dy);
+            if (dx, dy) == (0, 0) || nx < 0 || ny < 0 {
+                continue;
+            }
+            if input.get((nx as usize, ny as usize)) == Some(&Tile::Roll) {
+                rolls += 1;
+            }
+        }
+    }
+    input[(x, y)] == Tile::Roll && rolls < 4
+}
+
+fn first_accessible(input: &Grid<Tile>) -> Option<(usize, usize)> {
+    (0..input.height())
+        .flat_map(|y| (0..input.width()).map(move |x| (x, y)))
+        .find(|&(x, y)| accessible(input, x, y))
+}
+
+pub fn task1(input: &Grid<Tile>) -> usize {
+    (0..input.height())
+        .flat_map(|y| (0..input.width()).map(move |x| (x, y)))
+        .filter(|&(x, y)| accessible(input, x, y))
+        .count()
+}
+
+/// Removes one accessible roll at a time, rescanning the whole grid after each.
+pub fn task2(input: &Grid<Tile>) -> usize {
+    let mut input = input.clone();
+    let mut removed = 0;
+    while let Some(pos) = first_accessible(&input) {
+        input[pos] = Tile::Empty;
+        removed += 1;
+    }
+    removed
+}
+
+fn render(input: &Grid<Tile>) -> String {
+    input
+        .map(|t| match t {
+            Tile::Empty => '.',
+            Tile::Roll => '@',
+        })
+        .to_string()
+}
+
+/// Removes single rolls, and the last row or column.
+fn shrink(input: &Grid<Tile>) -> Vec<Grid<Tile>> {
+    let mut candidates = Vec::new();
+    for (pos, &t) in input.iter() {
+        if t == Tile::Roll {
+            let mut c = input.clone();
+            c[pos] = Tile::Empty;
+            candidates.push(c);
+        }
+    }
+    let text = render(input);
+    let lines: Vec<_> = text.lines().collect();
+    if lines.len() > 1 {
+        candidates.push(parse(&lines[..lines.len() - 1].join("\n")).unwrap());
+    }
+    if input.width() > 1 {
+        let narrower: Vec<_> = lines.iter().map(|l| &l[..l.len() - 1]).collect();
+        candidates.push(parse(&narrower.join("\n")).unwrap());
+    }
+    candidates
+}
+
+/// Compares both parts with neighbour counting and one-at-a-time removal on grids of up to
+/// 12×12.
+pub fn check(seeds: Range<u64>) -> Option<(u8, Mismatch<String, usize>)> {
+    let generate = |seed| parse(&generator::generate(seed, 1 + seed as usize % 12)).unwrap();
+    let parts: [(Solver, Solver); 2] = [
+        (|input| crate::task1(input).count(), task1),
+        (|input| crate::task2(input.clone()), task2),
+    ];
+    (1..).zip(parts).find_map(|(part, (solve, reference))| {
+        let m = differential(seeds.clone(), generate, shrink, solve, reference)?;
+        Some((part, m.map_input(render)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference() {
+        if let Some((part, m)) = check(0..2000) {
+            panic!("part {part}: {m}");
+        }
+    }
+}
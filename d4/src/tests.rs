@@ -13,21 +13,33 @@ const INPUT: &str = r"..@@.@@@@.
 
 #[test]
 fn test_task1() -> Result<()> {
-    let input = parse(INPUT);
-    let neighbor_cache = precompute_neighbors(input.len(), input[0].len());
-    assert_eq!(task1(&input, &neighbor_cache).count(), 13);
+    let input = parse(INPUT)?;
+    assert_eq!(task1(&input).count(), 13);
     Ok(())
 }
 
 #[test]
 fn test_task2() -> Result<()> {
-    let input = parse(INPUT);
-    let neighbor_cache = precompute_neighbors(input.len(), input[0].len());
-    assert_eq!(task2(input, &neighbor_cache), 43);
+    let input = parse(INPUT)?;
+    assert_eq!(task2(input), 43);
     Ok(())
 }
 
+#[test]
+fn test_crlf() -> Result<()> {
+    assert_eq!(parse(&INPUT.replace('\n', "\r\n"))?, parse(INPUT)?);
+    Ok(())
+}
+
+#[test]
+fn test_parse_error() {
+    let e = parse("..@@\n@@x.").unwrap_err();
+    let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+    assert_eq!((e.line, e.column), (2, 3));
+    assert_eq!(e.label, "unexpected `x`");
+}
+
 #[test]
 fn test_main() -> Result<()> {
-    main()
+    aoc::run::<Day4>()
 }
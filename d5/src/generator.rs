@@ -0,0 +1,51 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// `size` fresh ranges, often overlapping, then `size` ingredients, with IDs below `10 * size`.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let size = size.max(1);
+    let ranges: Vec<_> = (0..size)
+        .map(|_| {
+            let min = rng.random_range(0..10 * size);
+            format!("{min}-{}", min + rng.random_range(0..size))
+        })
+        .collect();
+    let ingredients: Vec<_> = (0..size)
+        .map(|_| rng.random_range(0..10 * size).to_string())
+        .collect();
+    format!("{}\n\n{}", ranges.join("\n"), ingredients.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, task1, task2};
+    use anyhow::Result;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = parse(&generate(1, 100))?;
+        assert_eq!((input.0.len(), input.1.len()), (100, 100));
+        let (ranges, ingredients) = &input;
+        assert!(
+            ranges
+                .iter()
+                .all(|&(min, max)| min <= max && max - min < 100 && min < 1000)
+        );
+        assert!(ingredients.iter().all(|&id| id < 1000));
+        assert!(task1(&input) <= 100);
+        assert!(task2(input) <= 100 * 100);
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_small() -> Result<()> {
+        let input = generate(0, 3);
+        assert_eq!(input, "19-21\n21-22\n15-17\n\n16\n1\n5");
+        // Only 16 is fresh, and 15-17 and 19-22 cover 7 IDs.
+        let input = parse(&input)?;
+        assert_eq!((task1(&input), task2(input)), (1, 7));
+        Ok(())
+    }
+}
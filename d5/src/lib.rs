@@ -0,0 +1,70 @@
+use anyhow::Result;
+use aoc::Solution;
+use std::fmt::Display;
+
+use winnow::combinator::{cut_err, separated, separated_pair};
+use winnow::error::{StrContext, StrContextValue::Description};
+use winnow::{Parser, ascii::dec_uint};
+
+pub mod generator;
+pub mod reference;
+pub use aoc::merge_ranges;
+
+pub type T = usize;
+/// Inclusive fresh ID ranges and the available ingredient IDs.
+pub type Input = (Vec<(T, T)>, Vec<T>);
+
+fn parse_(input: &mut &str) -> winnow::ModalResult<Input> {
+    let id = || dec_uint::<_, T, _>.context(StrContext::Expected(Description("an ID")));
+    // The range list is only committed to after its first ID, so the blank line can end it.
+    let range = separated_pair(
+        id(),
+        cut_err('-'.context(StrContext::Expected('-'.into()))),
+        cut_err(id()),
+    );
+    let parse_ranges = separated(1.., range, "\n");
+    let parse_ingredients = separated(1.., cut_err(id()), '\n');
+    let blank_line = "\n\n".context(StrContext::Expected(Description("a blank line")));
+    separated_pair(parse_ranges, blank_line, parse_ingredients).parse_next(input)
+}
+
+pub fn parse(input: &str) -> Result<Input> {
+    aoc::parse(parse_, input)
+}
+
+/// How many available ingredients fall into some fresh range.
+pub fn task1((ranges, ingredients): &Input) -> usize {
+    let pred = |&&i: &&usize| ranges.iter().any(|&(min, max)| min <= i && i <= max);
+    ingredients.iter().filter(pred).count()
+}
+
+/// How many IDs the fresh ranges cover, which is one more than `usize::MAX` when they cover
+/// every ID.
+pub fn task2((ranges, _): Input) -> u128 {
+    merge_ranges(ranges)
+        .iter()
+        .map(|&(min, max)| (max - min) as u128 + 1)
+        .sum()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task2(input.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,77 @@
+use aoc::check::{Mismatch, differential, without_one};
+use std::collections::HashSet;
+use std::ops::Range;
+
+use crate::{Input, generator, parse};
+
+type Solver = fn(&Input) -> u128;
+
+pub fn task1((ranges, ingredients): &Input) -> usize {
+    let fresh: HashSet<_> = ranges.iter().flat_map(|&(min, max)| min..=max).collect();
+    ingredients.iter().filter(|i| fresh.contains(i)).count()
+}
+
+/// Collects every fresh ID into a set.
+pub fn task2((ranges, _): &Input) -> u128 {
+    let fresh: HashSet<_> = ranges.iter().flat_map(|&(min, max)| min..=max).collect();
+    fresh.len() as u128
+}
+
+fn render((ranges, ingredients): &Input) -> String {
+    let ranges: Vec<_> = ranges
+        .iter()
+        .map(|(min, max)| format!("{min}-{max}"))
+        .collect();
+    let ingredients: Vec<_> = ingredients.iter().map(|i| i.to_string()).collect();
+    format!("{}\n\n{}", ranges.join("\n"), ingredients.join("\n"))
+}
+
+fn shrink((ranges, ingredients): &Input) -> Vec<Input> {
+    let mut candidates: Vec<_> = without_one(ranges, 1)
+        .into_iter()
+        .map(|r| (r, ingredients.clone()))
+        .collect();
+    candidates.extend(
+        without_one(ingredients, 1)
+            .into_iter()
+            .map(|i| (ranges.clone(), i)),
+    );
+    for (i, &(min, max)) in ranges.iter().enumerate() {
+        if min < max {
+            for narrower in [(min + 1, max), (min, max - 1)] {
+                let mut r = ranges.clone();
+                r[i] = narrower;
+                candidates.push((r, ingredients.clone()));
+            }
+        }
+    }
+    candidates
+}
+
+/// Compares both parts with set-based counting on inputs of up to 20 ranges and ingredients.
+pub fn check(seeds: Range<u64>) -> Option<(u8, Mismatch<String, u128>)> {
+    let generate = |seed| parse(&generator::generate(seed, 1 + seed as usize % 20)).unwrap();
+    let parts: [(Solver, Solver); 2] = [
+        (
+            |input| crate::task1(input) as u128,
+            |input| task1(input) as u128,
+        ),
+        (|input| crate::task2(input.clone()), task2),
+    ];
+    (1..).zip(parts).find_map(|(part, (solve, reference))| {
+        let m = differential(seeds.clone(), generate, shrink, solve, reference)?;
+        Some((part, m.map_input(render)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference() {
+        if let Some((part, m)) = check(0..2000) {
+            panic!("part {part}: {m}");
+        }
+    }
+}
@@ -45,7 +45,7 @@ fn test_task2() -> Result<()> {
 #[test_case("3-10\n2-4\n\n3", 9 ; "overlap_starts_before")]
 #[test_case("3-10\n9-11\n\n3", 9 ; "overlap_ends_after")]
 #[test_case("3-10\n1-12\n\n3", 12 ; "overlap_encompasses")]
-fn test_single_extra_range(input: &str, expected: usize) -> Result<()> {
+fn test_single_extra_range(input: &str, expected: u128) -> Result<()> {
     let input = parse(input)?;
     assert_eq!(task2(input), expected);
     Ok(())
@@ -58,7 +58,7 @@ fn test_single_extra_range(input: &str, expected: usize) -> Result<()> {
 #[test_case("1-10\n3-5\n7-12\n\n1", 12 ; "mix_contained_extending")]
 #[test_case("1-20\n5-7\n10-12\n15-17\n\n1", 20 ; "multiple_small_contained")]
 #[test_case("5-10\n\n1", 6 ; "single_range")]
-fn test_multiple_ranges(input: &str, expected: usize) -> Result<()> {
+fn test_multiple_ranges(input: &str, expected: u128) -> Result<()> {
     let input = parse(input)?;
     assert_eq!(task2(input), expected);
     Ok(())
@@ -70,7 +70,7 @@ fn test_multiple_ranges(input: &str, expected: usize) -> Result<()> {
 #[test_case("1-3\n2-4\n3-5\n4-6\n5-7\n\n1", 7 ; "many_small_connecting")]
 #[test_case("5-10\n5-10\n5-10\n\n1", 6 ; "duplicate_ranges")]
 #[test_case("1-3\n4-6\n7-9\n\n1", 9 ; "truly_adjacent")]
-fn test_complex_overlaps(input: &str, expected: usize) -> Result<()> {
+fn test_complex_overlaps(input: &str, expected: u128) -> Result<()> {
     let input = parse(input)?;
     assert_eq!(task2(input), expected);
     Ok(())
@@ -113,7 +113,35 @@ fn test_complex_overlaps(input: &str, expected: usize) -> Result<()> {
 // 5-10, 5-10, 5-10: [======] (3x)                    → 6  (duplicates)
 // 1-3, 4-6, 7-9: [===][===][===]                     → 9  (adjacent)
 
+#[test_case("0-18446744073709551615\n\n1", 1 << 64 ; "every_id")]
+#[test_case("0-9223372036854775807\n9223372036854775808-18446744073709551615\n\n1", 1 << 64 ; "every_id_in_halves")]
+#[test_case("1-18446744073709551615\n\n1", u64::MAX as u128 ; "every_id_but_zero")]
+fn test_wide_ranges(input: &str, expected: u128) -> Result<()> {
+    let input = parse(input)?;
+    assert_eq!(task2(input), expected);
+    Ok(())
+}
+
+#[test_case(vec![(10, 14), (3, 5), (16, 20), (12, 18)], vec![(3, 5), (10, 20)] ; "example")]
+#[test_case(vec![(1, 3), (4, 6), (8, 9)], vec![(1, 6), (8, 9)] ; "adjacent_and_gap")]
+#[test_case(vec![(1, 10), (3, 5)], vec![(1, 10)] ; "contained")]
+#[test_case(vec![(0, usize::MAX), (5, 5)], vec![(0, usize::MAX)] ; "full_range")]
+#[test_case(vec![], vec![] ; "empty")]
+fn test_merge_ranges(ranges: Vec<(T, T)>, expected: Vec<(T, T)>) {
+    assert_eq!(merge_ranges(ranges), expected);
+}
+
+#[test_case("3-5\n10x14\n\n1", (2, 3), "expected `-`, found `x`" ; "bad_separator")]
+#[test_case("3-5\n10-\n\n1", (2, 4), "expected an ID, found newline" ; "missing_end")]
+#[test_case("3-5", (1, 4), "expected a blank line, found end of input" ; "missing_ingredients")]
+#[test_case("3-5\n\n1\nx", (4, 1), "expected an ID, found `x`" ; "bad_ingredient")]
+fn test_parse_error(input: &str, (line, column): (usize, usize), label: &str) {
+    let e = parse(input).unwrap_err();
+    let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+    assert_eq!((e.line, e.column, e.label.as_str()), (line, column, label));
+}
+
 #[test]
 fn test_main() -> Result<()> {
-    main()
+    aoc::run::<Day5>()
 }
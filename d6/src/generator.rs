@@ -0,0 +1,61 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::fmt::Write;
+
+/// A worksheet of `size` problems side by side, each a column of 2 to 4 numbers of up to 4
+/// digits, all aligned left or all right, above its operator.
+///
+/// Like the real puzzle input, the digits are 1 to 9, so no column of a problem reads as 0.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let rows = rng.random_range(2..=4);
+    let mut lines = vec![String::new(); rows + 1];
+    for problem in 0..size.max(1) {
+        if problem > 0 {
+            lines.iter_mut().for_each(|line| line.push(' '));
+        }
+        let numbers: Vec<String> = (0..rows)
+            .map(|_| {
+                let digits = rng.random_range(1..=4);
+                (0..digits).map(|_| rng.random_range('1'..='9')).collect()
+            })
+            .collect();
+        let width = numbers.iter().map(String::len).max().unwrap();
+        let left = rng.random_bool(0.5);
+        for (line, n) in lines.iter_mut().zip(&numbers) {
+            match left {
+                true => write!(line, "{n:<width$}").unwrap(),
+                false => write!(line, "{n:>width$}").unwrap(),
+            }
+        }
+        let op = if rng.random_bool(0.5) { '+' } else { '*' };
+        write!(lines[rows], "{op:<width$}").unwrap();
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, task1, task2};
+    use anyhow::Result;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let text = generate(1, 50);
+        let widths: Vec<_> = text.lines().map(str::len).collect();
+        assert!(widths.windows(2).all(|w| w[0] == w[1]), "{widths:?}");
+        let input = parse(&text)?;
+        assert_eq!(input.len(), 50);
+        for (numbers, _) in &input {
+            assert!(numbers.iter().all(|&n| (1..10000).contains(&n)));
+        }
+        task2(&text)?;
+        let small = generate(0, 2);
+        assert_eq!(small, " 755 2589\n 612 5564\n6548    7\n*    +   ");
+        assert_eq!(task1(&parse(&small)?), 755 * 612 * 6548 + 2589 + 5564 + 7);
+        // Read by columns: 6 765 514 528, then 25 55 86 947.
+        assert_eq!(task2(&small)?, 6 * 765 * 514 * 528 + 25 + 55 + 86 + 947);
+        Ok(())
+    }
+}
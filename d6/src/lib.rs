@@ -0,0 +1,181 @@
+use anyhow::Result;
+use aoc::Solution;
+use std::fmt::Display;
+
+use winnow::Parser;
+use winnow::ascii::{dec_uint, line_ending, space0, space1};
+use winnow::combinator::{
+    alt, cut_err, delimited, eof, not, opt, separated, separated_pair, terminated,
+};
+use winnow::error::{StrContext, StrContextValue::Description};
+use winnow::token::{one_of, rest_len};
+
+pub mod generator;
+pub mod reference;
+
+pub type T = u64;
+/// Each problem's numbers, read row-wise, with its operator.
+pub type Input = Vec<(Vec<T>, char)>;
+
+/// A row's numbers and its end, each located by how much input is left from there.
+type Row = (Vec<(usize, T)>, usize);
+
+fn parse_(input: &mut &str) -> winnow::ModalResult<(Vec<Row>, Vec<char>)> {
+    let number = dec_uint::<_, T, _>.context(StrContext::Expected(Description("a number")));
+    let parse_row = separated(1.., (rest_len, number), space1).map(|v: Vec<_>| v);
+    let parse_nums = separated(
+        1..,
+        delimited(space0, (parse_row, rest_len), space0),
+        line_ending,
+    );
+    let op = one_of(['+', '*'])
+        .context(StrContext::Expected('+'.into()))
+        .context(StrContext::Expected('*'.into()));
+    // Spaces before the end of the row must lead to another operator.
+    let gap = (space1, not(alt((line_ending.void(), eof.void()))));
+    let parse_ops = separated(1.., cut_err(op), gap);
+    let newline = line_ending.context(StrContext::Expected('\n'.into()));
+    let end = (space0, opt(line_ending));
+    separated_pair(parse_nums, newline, terminated(parse_ops, end)).parse_next(input)
+}
+
+/// Parses the worksheet row-wise; every row needs one number per operator.
+pub fn parse(input: &str) -> Result<Input> {
+    let (rows, ops) = aoc::parse(parse_, input)?;
+    for (row, end) in &rows {
+        if row.len() != ops.len() {
+            // Point at the first extra number, or where the missing ones should be.
+            let rest = row.get(ops.len()).map_or(*end, |&(rest, _)| rest);
+            let numbers = if ops.len() == 1 { "number" } else { "numbers" };
+            let label = format!("expected {} {numbers}, found {}", ops.len(), row.len());
+            return Err(aoc::ParseError::new(input, input.len() - rest, label).into());
+        }
+    }
+    Ok((0..ops.len())
+        .map(|i| rows.iter().map(|(row, _)| row[i].1).collect())
+        .zip(ops)
+        .collect())
+}
+
+pub fn task1(input: &Input) -> T {
+    input
+        .iter()
+        .map(|(row, op)| match op {
+            '+' => row.iter().sum::<T>(),
+            '*' => row.iter().product(),
+            _ => unreachable!(),
+        })
+        .sum()
+}
+
+/// Reads the numbers column-wise from the raw worksheet, right to left.
+pub fn task2(input: &str) -> Result<T> {
+    let data: Vec<_> = input.lines().map(str::as_bytes).collect();
+    let (height, width, mut ans, mut nums) = (data.len(), data[0].len(), 0, Vec::new());
+
+    for col in (0..width).rev() {
+        let num = data[..height - 1]
+            .iter()
+            .filter_map(|row| row.get(col).filter(|&n| n.is_ascii_digit()))
+            .fold(0, |acc, n| acc * 10 + (n - b'0') as T);
+        if num != 0 {
+            nums.push(num);
+        }
+
+        ans += match data[height - 1].get(col) {
+            Some(b'+') => nums.iter().sum::<T>(),
+            Some(b'*') => nums.iter().product::<T>(),
+            Some(b' ') | None => continue,
+            Some(_) => {
+                let line = input.trim_end().rfind('\n').map_or(0, |i| i + 1);
+                let offset = input.floor_char_boundary(line + col);
+                let found = aoc::ParseError::found(input, offset);
+                let label = format!("expected `+` or `*`, found {found}");
+                return Err(aoc::ParseError::new(input, offset, label).into());
+            }
+        };
+        nums.clear();
+    }
+    Ok(ans)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    // Part 2 reads the columns of the raw text, so keep it alongside the parsed rows.
+    type Input<'a> = (Input, &'a str);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok((parse(input)?, input))
+    }
+
+    fn part1((input, _): &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task1(input))
+    }
+
+    fn part2((_, s): &Self::Input<'_>) -> Result<impl Display> {
+        task2(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
+
+    #[test]
+    fn test_task1() -> Result<()> {
+        let input = parse(INPUT)?;
+        assert_eq!(task1(&input), 4277556);
+        Ok(())
+    }
+
+    #[test]
+    fn test_task2() -> Result<()> {
+        assert_eq!(task2(INPUT)?, 3263827);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse("1 2\n3 4\n* -").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (3, 3));
+        assert_eq!(e.label, "expected `+` or `*`, found `-`");
+        let e = parse("1 2\n3\n* +").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (2, 2));
+        assert_eq!(e.label, "expected 2 numbers, found 1");
+        let e = parse("1\n 2 34\n*").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (2, 4));
+        assert_eq!(e.label, "expected 1 number, found 2");
+    }
+
+    #[test]
+    fn test_task2_bad_operator() {
+        let e = task2("12 3\n 4 5\n*  -").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (3, 4));
+        assert_eq!(e.label, "expected `+` or `*`, found `-`");
+        let e = task2("12 3\n 4 5\n*  é\n").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (3, 4));
+        assert_eq!(e.label, "expected `+` or `*`, found `é`");
+    }
+
+    #[test]
+    fn test_crlf() -> Result<()> {
+        let crlf = format!("{INPUT}\n").replace('\n', "\r\n");
+        assert_eq!(parse(&crlf)?, parse(INPUT)?);
+        assert_eq!(task2(&crlf)?, task2(INPUT)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_main() -> Result<()> {
+        aoc::run::<Day6>()
+    }
+}
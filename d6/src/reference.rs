@@ -0,0 +1,117 @@
+use aoc::check::{Mismatch, differential};
+use std::ops::Range;
+
+use crate::{T, generator};
+
+type Solver = fn(&String) -> T;
+
+fn apply(op: char, numbers: impl Iterator<Item = T>) -> T {
+    match op {
+        '+' => numbers.sum(),
+        _ => numbers.product(),
+    }
+}
+
+/// Splits every row on whitespace and takes the `i`-th word of each.
+pub fn task1(input: &str) -> T {
+    let rows: Vec<Vec<&str>> = input
+        .lines()
+        .map(|l| l.split_whitespace().collect())
+        .collect();
+    let (ops, numbers) = rows.split_last().unwrap();
+    (ops.iter().enumerate())
+        .map(|(i, op)| {
+            let column = numbers.iter().map(|row| row[i].parse::<T>().unwrap());
+            apply(op.chars().next().unwrap(), column)
+        })
+        .sum()
+}
+
+/// Cuts the worksheet into problems at the blank columns, then reads each column of a problem
+/// top to bottom as one number, skipping those with no digits.
+pub fn task2(input: &str) -> T {
+    let lines: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+    let width = lines.iter().map(Vec::len).max().unwrap();
+    let columns: Vec<String> = (0..width)
+        .map(|x| lines.iter().map(|l| l.get(x).unwrap_or(&' ')).collect())
+        .collect();
+    columns
+        .split(|c| c.trim().is_empty())
+        .filter(|problem| !problem.is_empty())
+        .map(|problem| {
+            let op = problem[0].chars().last().unwrap();
+            let numbers = problem
+                .iter()
+                .filter_map(|c| c[..c.len() - 1].replace(' ', "").parse().ok());
+            apply(op, numbers)
+        })
+        .sum()
+}
+
+/// Drops a problem, or a row of numbers, keeping at least one of each.
+fn shrink(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut candidates = Vec::new();
+    let width = lines.iter().map(|l| l.len()).max().unwrap();
+    let blank = |x: usize| {
+        lines
+            .iter()
+            .all(|l| l.as_bytes().get(x).is_none_or(|&b| b == b' '))
+    };
+    let starts: Vec<_> = (0..width)
+        .filter(|&x| !blank(x) && (x == 0 || blank(x - 1)))
+        .collect();
+    for (i, &start) in starts.iter().enumerate() {
+        if starts.len() == 1 {
+            break;
+        }
+        // A problem and the blank column after it, or before it for the last one.
+        let (from, to) = match starts.get(i + 1) {
+            Some(&next) => (start, next),
+            None => (start - 1, width),
+        };
+        let cut: Vec<String> = (lines.iter())
+            .map(|l| {
+                let l = format!("{l:<width$}");
+                format!("{}{}", &l[..from], &l[to..])
+            })
+            .collect();
+        candidates.push(cut.join("\n"));
+    }
+    if lines.len() > 2 {
+        for i in 0..lines.len() - 1 {
+            let rows = [&lines[..i], &lines[i + 1..]].concat();
+            candidates.push(rows.join("\n"));
+        }
+    }
+    candidates
+}
+
+/// Compares both parts with whitespace splitting and column cutting on worksheets of up to 8
+/// problems.
+pub fn check(seeds: Range<u64>) -> Option<(u8, Mismatch<String, T>)> {
+    let generate = |seed| generator::generate(seed, 1 + seed as usize % 8);
+    let parts: [(Solver, Solver); 2] = [
+        (
+            |input| crate::task1(&crate::parse(input).unwrap()),
+            |i| task1(i),
+        ),
+        (|input| crate::task2(input).unwrap(), |i| task2(i)),
+    ];
+    (1..).zip(parts).find_map(|(part, (solve, reference))| {
+        let shrink = |input: &String| shrink(input);
+        differential(seeds.clone(), generate, shrink, solve, reference).map(|m| (part, m))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference() {
+        if let Some((part, m)) = check(0..2000) {
+            panic!("part {part}: {m}");
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// A manifold `2 * size + 1` tiles wide with `S` in the middle of the first row and `size`
+/// rows of splitters, each below an empty row.
+///
+/// Splitters never sit in the outer columns, so split beams always stay inside.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let size = size.max(1);
+    let width = 2 * size + 1;
+    let mut rows = vec![format!("{0}S{0}", ".".repeat(size))];
+    for _ in 0..size {
+        rows.push(".".repeat(width));
+        let splitters = (0..width).map(|x| {
+            let inside = 0 < x && x < width - 1;
+            if inside && rng.random_bool(0.4) {
+                '^'
+            } else {
+                '.'
+            }
+        });
+        rows.push(splitters.collect());
+    }
+    rows.push(".".repeat(width));
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Tile, parse, task1, task2};
+    use anyhow::Result;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = parse(&generate(1, 30))?;
+        assert_eq!((input.width(), input.height()), (61, 62));
+        for ((x, y), &tile) in input.iter() {
+            match tile {
+                Tile::Start => assert_eq!((x, y), (30, 0)),
+                Tile::Split => assert!(y % 2 == 0 && 0 < x && x < 60, "splitter at {x},{y}"),
+                Tile::Empty => {}
+            }
+        }
+        assert!(task1(&input) < task2(&input));
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_small() -> Result<()> {
+        let input = generate(1, 2);
+        assert_eq!(input, "..S..\n.....\n..^..\n.....\n.^^..\n.....");
+        // The beam splits left and right, then only the left beam hits a splitter.
+        let input = parse(&input)?;
+        assert_eq!((task1(&input), task2(&input)), (2, 3));
+        Ok(())
+    }
+}
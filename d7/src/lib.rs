@@ -0,0 +1,149 @@
+use anyhow::{Result, ensure};
+use aoc::{Grid, Solution};
+use std::{collections::HashSet, fmt::Display};
+
+pub mod generator;
+pub mod reference;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    Split,
+    Start,
+}
+
+pub type T = u64;
+
+/// Parses the manifold; the first row must contain the `S` start.
+pub fn parse(input: &str) -> Result<Grid<Tile>> {
+    let grid = Grid::parse(input, |c| match c {
+        '.' => Some(Tile::Empty),
+        '^' => Some(Tile::Split),
+        'S' => Some(Tile::Start),
+        _ => None,
+    })?;
+    ensure!(
+        grid.row(0).contains(&Tile::Start),
+        "no `S` in the first row"
+    );
+    Ok(grid)
+}
+
+/// How many times the beam is split.
+pub fn task1(input: &Grid<Tile>) -> T {
+    let mut beams = Vec::from([input.row(0).iter().position(|&t| t == Tile::Start).unwrap()]);
+    let mut ans = 0;
+
+    for l in input.rows().skip(1) {
+        beams = beams
+            .into_iter()
+            .flat_map(|i| match l[i] {
+                Tile::Empty | Tile::Start => vec![i],
+                Tile::Split => {
+                    ans += 1;
+                    vec![i - 1, i + 1]
+                }
+            })
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+    }
+    ans
+}
+
+/// How many timelines a single particle ends up in.
+pub fn task2(input: &Grid<Tile>) -> T {
+    let mut beams = vec![0; input.width()];
+    beams[input.row(0).iter().position(|&t| t == Tile::Start).unwrap()] = 1;
+
+    for l in input.rows().skip(1) {
+        let mut new_beams = vec![0; input.width()];
+        for (i, v) in l.iter().enumerate() {
+            match v {
+                Tile::Empty | Tile::Start => new_beams[i] += beams[i],
+                Tile::Split => {
+                    new_beams[i - 1] += beams[i];
+                    new_beams[i + 1] += beams[i];
+                }
+            }
+        }
+        beams = new_beams;
+    }
+    beams.iter().sum()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input<'a> = Grid<Tile>;
+
+    fn parse(input: &str) -> Result<Grid<Tile>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+    #[test]
+    fn test_task1() -> Result<()> {
+        let input = parse(INPUT)?;
+        assert_eq!(task1(&input), 21);
+        Ok(())
+    }
+
+    #[test]
+    fn test_task2() -> Result<()> {
+        let input = parse(INPUT)?;
+        assert_eq!(task2(&input), 40);
+        Ok(())
+    }
+
+    #[test]
+    fn test_crlf() -> Result<()> {
+        assert_eq!(parse(&INPUT.replace('\n', "\r\n"))?, parse(INPUT)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse(".S.\n.^.\n.^ ").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (3, 3));
+        assert_eq!(e.label, "unexpected ` `");
+        let e = parse("...\n.S.").unwrap_err();
+        assert_eq!(e.to_string(), "no `S` in the first row");
+    }
+
+    #[test]
+    fn test_main() -> Result<()> {
+        aoc::run::<Day7>()
+    }
+}
@@ -0,0 +1,89 @@
+use aoc::Grid;
+use aoc::check::{Mismatch, differential};
+use std::collections::HashSet;
+use std::ops::Range;
+
+use crate::{T, Tile, generator, parse};
+
+type Solver = fn(&Grid<Tile>) -> T;
+
+/// Follows every path a particle can take, one at a time, returning the splitters it can
+/// reach and the number of paths.
+fn enumerate(input: &Grid<Tile>) -> (HashSet<(usize, usize)>, T) {
+    let start = input.row(0).iter().position(|&t| t == Tile::Start).unwrap();
+    let (mut reached, mut paths) = (HashSet::new(), 0);
+    let mut stack = vec![(start, 0)];
+    while let Some((x, y)) = stack.pop() {
+        if y + 1 == input.height() {
+            paths += 1;
+            continue;
+        }
+        match input[(x, y + 1)] {
+            Tile::Empty | Tile::Start => stack.push((x, y + 1)),
+            Tile::Split => {
+                reached.insert((x, y + 1));
+                stack.extend([(x - 1, y + 1), (x + 1, y + 1)]);
+            }
+        }
+    }
+    (reached, paths)
+}
+
+pub fn task1(input: &Grid<Tile>) -> T {
+    enumerate(input).0.len() as T
+}
+
+pub fn task2(input: &Grid<Tile>) -> T {
+    enumerate(input).1
+}
+
+fn render(input: &Grid<Tile>) -> String {
+    input
+        .map(|t| match t {
+            Tile::Empty => '.',
+            Tile::Split => '^',
+            Tile::Start => 'S',
+        })
+        .to_string()
+}
+
+/// Removes single splitters, and rows below the first.
+fn shrink(input: &Grid<Tile>) -> Vec<Grid<Tile>> {
+    let mut candidates = Vec::new();
+    for (pos, &t) in input.iter() {
+        if t == Tile::Split {
+            let mut c = input.clone();
+            c[pos] = Tile::Empty;
+            candidates.push(c);
+        }
+    }
+    let text = render(input);
+    let lines: Vec<_> = text.lines().collect();
+    for y in 1..lines.len() {
+        let rows = [&lines[..y], &lines[y + 1..]].concat();
+        candidates.push(parse(&rows.join("\n")).unwrap());
+    }
+    candidates
+}
+
+/// Compares both parts with path enumeration on manifolds of up to 8 splitter rows.
+pub fn check(seeds: Range<u64>) -> Option<(u8, Mismatch<String, T>)> {
+    let generate = |seed| parse(&generator::generate(seed, 1 + seed as usize % 8)).unwrap();
+    let parts: [(Solver, Solver); 2] = [(crate::task1, task1), (crate::task2, task2)];
+    (1..).zip(parts).find_map(|(part, (solve, reference))| {
+        let m = differential(seeds.clone(), generate, shrink, solve, reference)?;
+        Some((part, m.map_input(render)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference() {
+        if let Some((part, m)) = check(0..2000) {
+            panic!("part {part}: {m}");
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
+
+/// `size` junction boxes, at least one, at distinct positions with coordinates below 100 000.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut seen = HashSet::new();
+    let mut lines = Vec::with_capacity(size.max(1));
+    while lines.len() < size.max(1) {
+        let pos: [u32; 3] = rng.random::<[u32; 3]>().map(|c| c % 100_000);
+        if seen.insert(pos) {
+            lines.push(format!("{},{},{}", pos[0], pos[1], pos[2]));
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_sorted_pairs, parse, task1, task2};
+    use anyhow::Result;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = parse(&generate(1, 100))?;
+        assert_eq!(input.len(), 100);
+        let distinct: HashSet<_> = input.iter().collect();
+        assert_eq!(distinct.len(), 100);
+        assert!(input.iter().all(|&(x, y, z)| x.max(y).max(z) < 100_000));
+        assert_eq!(parse(&generate(1, 0))?.len(), 1);
+
+        let small = parse(&generate(0, 3))?;
+        let pairs = get_sorted_pairs(&small);
+        assert_eq!(task1(&small, &pairs, 1), 2);
+        assert_eq!(task2(&small, &pairs)?, 98719824);
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use anyhow::{Context, Result};
+use aoc::Solution;
+use rayon::slice::ParallelSliceMut;
+use std::fmt::Display;
+
+use winnow::combinator::{cut_err, separated, seq};
+use winnow::error::{StrContext, StrContextValue::Description};
+use winnow::{Parser, ascii::dec_uint};
+
+pub mod generator;
+pub mod reference;
+mod union_find;
+pub use union_find::UnionFind;
+
+pub type Pos = (usize, usize, usize);
+
+fn parse_(input: &mut &str) -> winnow::ModalResult<Vec<Pos>> {
+    let coord = || dec_uint.context(StrContext::Expected(Description("a coordinate")));
+    let comma = || ','.context(StrContext::Expected(','.into()));
+    let three_nums = seq!(coord(), _: comma(), coord(), _: comma(), coord());
+    separated(1.., cut_err(three_nums), "\n").parse_next(input)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Pos>> {
+    aoc::parse(parse_, input)
+}
+
+/// All index pairs `(i, j)` with `i < j`, closest first.
+pub fn get_sorted_pairs(input: &[Pos]) -> Vec<(usize, usize)> {
+    let n = input.len();
+    let mut pairs: Vec<_> = (0..n)
+        .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+        .collect();
+    pairs.par_sort_by_key(|(i, j)| {
+        let (x1, y1, z1) = input[*i];
+        let (x2, y2, z2) = input[*j];
+        x1.abs_diff(x2).pow(2) + y1.abs_diff(y2).pow(2) + z1.abs_diff(z2).pow(2)
+    });
+    pairs
+}
+
+/// Connects the `n` closest pairs, or every pair if there are fewer, and multiplies the sizes
+/// of the three largest circuits.
+pub fn task1(input: &[Pos], pairs: &[(usize, usize)], n: usize) -> usize {
+    let mut uf = UnionFind::new(input.len());
+    for &(i, j) in pairs.iter().take(n) {
+        uf.union(i, j);
+    }
+    let mut groups: Vec<_> = uf.groups().into_iter().map(|group| group.len()).collect();
+    groups.sort_unstable();
+    groups.into_iter().rev().take(3).product()
+}
+
+/// Multiplies the X coordinates of the pair whose connection forms a single circuit. Fails on
+/// a single box, which has no pair to connect.
+pub fn task2(input: &[Pos], pairs: &[(usize, usize)]) -> Result<usize> {
+    let mut uf = UnionFind::new(input.len());
+    let (i, j) = (pairs.iter().copied())
+        .find(|&(i, j)| {
+            uf.union(i, j);
+            uf.one_group()
+        })
+        .context("no pair of junction boxes to connect")?;
+    Ok(input[i].0 * input[j].0)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input<'a> = (Vec<Pos>, Vec<(usize, usize)>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let input = parse(input)?;
+        let pairs = get_sorted_pairs(&input);
+        Ok((input, pairs))
+    }
+
+    fn part1((input, pairs): &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task1(input, pairs, 1000))
+    }
+
+    fn part2((input, pairs): &Self::Input<'_>) -> Result<impl Display> {
+        task2(input, pairs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r"162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+
+    #[test]
+    fn test_task1() -> Result<()> {
+        let input = parse(INPUT)?;
+        assert_eq!(task1(&input, &get_sorted_pairs(&input), 10), 40);
+        Ok(())
+    }
+
+    #[test]
+    fn test_task2() -> Result<()> {
+        let input = parse(INPUT)?;
+        assert_eq!(task2(&input, &get_sorted_pairs(&input))?, 25272);
+        Ok(())
+    }
+
+    #[test]
+    fn test_one_box() -> Result<()> {
+        let input = parse("162,817,812")?;
+        let pairs = get_sorted_pairs(&input);
+        assert_eq!(task1(&input, &pairs, 10), 1);
+        let e = task2(&input, &pairs).unwrap_err();
+        assert_eq!(e.to_string(), "no pair of junction boxes to connect");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse("162,817,812\n57,618").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (2, 7));
+        assert_eq!(e.label, "expected `,`, found end of input");
+    }
+
+    #[test]
+    fn test_main() -> Result<()> {
+        aoc::run::<Day8>()
+    }
+}
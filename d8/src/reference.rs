@@ -0,0 +1,107 @@
+use aoc::check::{Mismatch, differential, without_one};
+use std::ops::Range;
+
+use crate::{Pos, generator, get_sorted_pairs, parse};
+
+type Solver = fn(&Vec<Pos>) -> String;
+
+/// Every pair, closest first and then by index, with the squared distances worked out in
+/// `u128`.
+fn pairs(input: &[Pos]) -> Vec<(usize, usize)> {
+    let square = |a: usize, b: usize| (a.abs_diff(b) as u128).pow(2);
+    let mut pairs = Vec::new();
+    for i in 0..input.len() {
+        for j in i + 1..input.len() {
+            let ((x1, y1, z1), (x2, y2, z2)) = (input[i], input[j]);
+            pairs.push((square(x1, x2) + square(y1, y2) + square(z1, z2), i, j));
+        }
+    }
+    pairs.sort_unstable();
+    pairs.into_iter().map(|(_, i, j)| (i, j)).collect()
+}
+
+/// Labels every box with its circuit, relabelling one whole circuit on each connection.
+fn connect(labels: &mut [usize], (i, j): (usize, usize)) {
+    let (from, to) = (labels[j], labels[i]);
+    for label in labels.iter_mut().filter(|l| **l == from) {
+        *label = to;
+    }
+}
+
+/// How many pairs [`check`] connects in part 1: ten, as in the example, or all of them.
+fn connections(input: &[Pos]) -> usize {
+    (input.len() * (input.len() - 1) / 2).min(10)
+}
+
+pub fn task1(input: &[Pos], n: usize) -> usize {
+    let mut labels: Vec<_> = (0..input.len()).collect();
+    for pair in pairs(input).into_iter().take(n) {
+        connect(&mut labels, pair);
+    }
+    let mut sizes: Vec<_> = (0..input.len())
+        .map(|l| labels.iter().filter(|&&x| x == l).count())
+        .filter(|&size| size > 0)
+        .collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes[..3.min(sizes.len())].iter().product()
+}
+
+/// `None` for a single box, which has no pair to connect.
+pub fn task2(input: &[Pos]) -> Option<usize> {
+    let mut labels: Vec<_> = (0..input.len()).collect();
+    for (i, j) in pairs(input) {
+        connect(&mut labels, (i, j));
+        if labels.iter().all(|&l| l == labels[0]) {
+            return Some(input[i].0 * input[j].0);
+        }
+    }
+    None
+}
+
+/// An answer, or `none` where there is none, so both sides of the comparison can be missing.
+fn answer(answer: Option<usize>) -> String {
+    answer.map_or("none".to_string(), |a| a.to_string())
+}
+
+fn render(input: &[Pos]) -> String {
+    let lines: Vec<_> = input
+        .iter()
+        .map(|(x, y, z)| format!("{x},{y},{z}"))
+        .collect();
+    lines.join("\n")
+}
+
+/// Compares both parts with relabelling on up to 20 junction boxes.
+pub fn check(seeds: Range<u64>) -> Option<(u8, Mismatch<String, String>)> {
+    let generate = |seed| parse(&generator::generate(seed, 1 + seed as usize % 20)).unwrap();
+    let parts: [(Solver, Solver); 2] = [
+        (
+            |input| {
+                let pairs = get_sorted_pairs(input);
+                crate::task1(input, &pairs, connections(input)).to_string()
+            },
+            |input| task1(input, connections(input)).to_string(),
+        ),
+        (
+            |input| answer(crate::task2(input, &get_sorted_pairs(input)).ok()),
+            |input| answer(task2(input)),
+        ),
+    ];
+    (1..).zip(parts).find_map(|(part, (solve, reference))| {
+        let shrink = |input: &Vec<Pos>| without_one(input, 1);
+        let m = differential(seeds.clone(), generate, shrink, solve, reference)?;
+        Some((part, m.map_input(|i| render(i))))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference() {
+        if let Some((part, m)) = check(0..2000) {
+            panic!("part {part}: {m}");
+        }
+    }
+}
@@ -1,3 +1,4 @@
+/// Disjoint sets over `0..n`, with path compression and union by rank.
 pub struct UnionFind {
     parent: Vec<usize>,
     rank: Vec<usize>,
@@ -18,6 +19,7 @@ impl UnionFind {
         self.parent[x]
     }
 
+    /// Merges the sets of `x` and `y`, returning `false` if they were already joined.
     pub fn union(&mut self, x: usize, y: usize) -> bool {
         let root_x = self.find(x);
         let root_y = self.find(y);
@@ -0,0 +1,97 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// A simple rectilinear polygon with about `size` red corners, listed in order around it.
+///
+/// The polygon is a run of `size / 4` adjacent columns, each spanning its own vertical
+/// interval that overlaps its neighbours', traced along the top and back along the bottom.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let columns = (size / 4).max(1);
+
+    let mut xs: Vec<usize> = vec![rng.random_range(0..1000)];
+    let mut spans: Vec<(usize, usize)> = Vec::new();
+    for _ in 0..columns {
+        xs.push(xs.last().unwrap() + rng.random_range(1..1000));
+        let span = loop {
+            let (a, b) = (rng.random_range(0..100_000), rng.random_range(0..100_000));
+            let span = (a.min(b), a.max(b));
+            let fits = spans.last().is_none_or(|&(bottom, top)| {
+                span.0 != bottom && span.1 != top && span.0.max(bottom) < span.1.min(top)
+            });
+            if span.0 < span.1 && fits {
+                break span;
+            }
+        };
+        spans.push(span);
+    }
+
+    let mut corners = vec![(xs[0], spans[0].0)];
+    for (i, &(_, top)) in spans.iter().enumerate() {
+        corners.extend([(xs[i], top), (xs[i + 1], top)]);
+    }
+    for (i, &(bottom, _)) in spans.iter().enumerate().rev() {
+        corners.extend([(xs[i + 1], bottom), (xs[i], bottom)]);
+    }
+    // The bottom edge ends back at the first corner.
+    corners.pop();
+    corners
+        .iter()
+        .map(|(x, y)| format!("{x},{y}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, task1, task2};
+    use anyhow::Result;
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let input = parse(&generate(1, 40))?;
+        assert_eq!(input.len(), 40);
+        // The edges, closing the loop back to the first corner.
+        let edges: Vec<_> = (0..input.len())
+            .map(|i| (input[i], input[(i + 1) % input.len()]))
+            .collect();
+        for (i, &((x1, y1), (x2, y2))) in edges.iter().enumerate() {
+            assert!(
+                (x1 == x2) != (y1 == y2),
+                "{:?} isn't axis-aligned",
+                edges[i]
+            );
+            let ((x3, _), (x4, _)) = edges[(i + 1) % edges.len()];
+            assert!((x1 == x2) != (x3 == x4), "no turn after {:?}", edges[i]);
+        }
+        // Only neighbouring edges meet, so the loop is simple.
+        let overlap = |(a, b): (usize, usize), (c, d): (usize, usize)| {
+            a.min(b).max(c.min(d)) <= a.max(b).min(c.max(d))
+        };
+        for (i, &((x1, y1), (x2, y2))) in edges.iter().enumerate() {
+            for j in i + 2..edges.len() - (i == 0) as usize {
+                let ((x3, y3), (x4, y4)) = edges[j];
+                let meet = overlap((x1, x2), (x3, x4)) && overlap((y1, y2), (y3, y4));
+                assert!(!meet, "{:?} meets {:?}", edges[i], edges[j]);
+            }
+        }
+        assert!(task2(&input) <= task1(&input));
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_small() -> Result<()> {
+        let input = generate(0, 8);
+        assert_eq!(
+            input,
+            "654,46592\n654,72986\n1363,72986\n1363,69914\n\
+             1870,69914\n1870,56096\n1363,56096\n1363,46592"
+        );
+        // The largest rectangle pokes out below the short column, so only the tall one fits.
+        let input = parse(&input)?;
+        assert_eq!(task1(&input), 1217 * 23323);
+        assert_eq!(task2(&input), 710 * 26395);
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+use anyhow::Result;
+use aoc::grid::Pos;
+use aoc::{Grid, Solution};
+use itertools::Itertools;
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Display};
+
+use winnow::combinator::{cut_err, separated, separated_pair};
+use winnow::error::{StrContext, StrContextValue::Description};
+use winnow::{Parser, ascii::dec_uint};
+
+pub mod generator;
+pub mod reference;
+
+fn parse_(input: &mut &str) -> winnow::ModalResult<Vec<Pos>> {
+    let coord = || dec_uint.context(StrContext::Expected(Description("a coordinate")));
+    let pos = separated_pair(
+        coord(),
+        ','.context(StrContext::Expected(','.into())),
+        coord(),
+    );
+    separated(1.., cut_err(pos), '\n').parse_next(input)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Pos>> {
+    aoc::parse(parse_, input)
+}
+
+/// The largest rectangle with red tiles in two opposite corners.
+pub fn task1(input: &[Pos]) -> usize {
+    input
+        .iter()
+        .enumerate()
+        .flat_map(|(i, p1)| input.iter().skip(i + 1).map(move |p2| (*p1, *p2)))
+        .map(|((x1, y1), (x2, y2))| (x2.abs_diff(x1) + 1) * (y2.abs_diff(y1) + 1))
+        .max()
+        .unwrap()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Unknown,
+    Empty,
+    Red,
+    Green,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "_"),
+            Self::Empty => write!(f, "."),
+            Self::Red => write!(f, "#"),
+            Self::Green => write!(f, "X"),
+        }
+    }
+}
+
+fn flood_fill(map: &mut Grid<Tile>, pos: Pos, tile: Tile) {
+    let mut queue = VecDeque::from([pos]);
+
+    while let Some(pos) = queue.pop_front() {
+        if let Some(t @ Tile::Unknown) = map.get_mut(pos) {
+            *t = tile;
+            queue.extend(map.neighbors4(pos).filter(|&n| map[n] == Tile::Unknown));
+        }
+    }
+}
+
+/// Maps indices from 1 to the distinct `values` in order, skipping an index between two values
+/// that have tiles between them, so those tiles are represented too.
+fn compress(values: impl Iterator<Item = usize>) -> HashMap<usize, usize> {
+    let mut last: Option<(usize, usize)> = None;
+    let mut indices = HashMap::new();
+    for v in values.unique().sorted() {
+        let i = match last {
+            None => 1,
+            Some((i, prev)) if v - prev == 1 => i + 1,
+            Some((i, _)) => i + 2,
+        };
+        indices.insert(i, v);
+        last = Some((i, v));
+    }
+    indices
+}
+
+/// Like [`task1`], but the rectangle must lie within the loop the red tiles draw.
+pub fn task2(input: &[Pos]) -> usize {
+    use Tile::*;
+
+    let xs = compress(input.iter().map(|&(x, _)| x));
+    let ys = compress(input.iter().map(|&(_, y)| y));
+    let xs_inv: HashMap<usize, usize> = xs.iter().map(|(&k, &v)| (v, k)).collect();
+    let ys_inv: HashMap<usize, usize> = ys.iter().map(|(&k, &v)| (v, k)).collect();
+
+    let input: Vec<_> = input.iter().map(|(x, y)| (xs_inv[x], ys_inv[y])).collect();
+
+    let (width, height) = (xs.keys().max().unwrap() + 2, ys.keys().max().unwrap() + 2);
+    let mut map = Grid::new(width, height, Unknown);
+
+    let wrap = [*input.first().unwrap(), *input.last().unwrap()];
+    for edge in input.windows(2).chain([&wrap[..]]) {
+        let ((x1, y1), (x2, y2)) = (edge[0], edge[1]);
+        map[(x1, y1)] = Red;
+        map[(x2, y2)] = Red;
+        if x1 == x2 {
+            for y in y1.min(y2) + 1..y1.max(y2) {
+                map[(x1, y)] = Green;
+            }
+        } else {
+            for x in x1.min(x2) + 1..x1.max(x2) {
+                map[(x, y1)] = Green;
+            }
+        }
+    }
+
+    flood_fill(&mut map, (0, 0), Empty);
+    for t in map.iter_mut() {
+        if *t == Unknown {
+            *t = Green;
+        }
+    }
+
+    input
+        .par_iter()
+        .enumerate()
+        .flat_map(|(i, p1)| input.par_iter().skip(i + 1).map(move |p2| (*p1, *p2)))
+        .filter(|&((x1, y1), (x2, y2))| {
+            (y1.min(y2)..=y1.max(y2))
+                .all(|y| (x1.min(x2)..=x1.max(x2)).all(|x| matches!(map[(x, y)], Red | Green)))
+        })
+        .map(|((x1, y1), (x2, y2))| {
+            (xs[&x2].abs_diff(xs[&x1]) + 1) * (ys[&y2].abs_diff(ys[&y1]) + 1)
+        })
+        .max()
+        .unwrap()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input<'a> = Vec<Pos>;
+
+    fn parse(input: &str) -> Result<Vec<Pos>> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<impl Display> {
+        Ok(task2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r"7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3";
+
+    #[test]
+    fn test_task1() -> Result<()> {
+        let input = parse(INPUT)?;
+        assert_eq!(task1(&input), 50);
+        Ok(())
+    }
+
+    #[test]
+    fn test_task2() -> Result<()> {
+        let input = parse(INPUT)?;
+        assert_eq!(task2(&input), 24);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse("7,1\n11;1").unwrap_err();
+        let e = e.downcast_ref::<aoc::ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(e.label, "expected `,`, found `;`");
+    }
+
+    #[test]
+    fn test_main() -> Result<()> {
+        aoc::run::<Day9>()
+    }
+}
@@ -0,0 +1,107 @@
+use aoc::check::{Mismatch, differential};
+use aoc::grid::Pos;
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+use crate::{generator, parse};
+
+type Solver = fn(&Vec<Pos>) -> usize;
+
+/// Whether `(x, y)` lies on the loop or inside it, counting the vertical edges a ray to the
+/// right crosses.
+fn inside(input: &[Pos], (x, y): Pos) -> bool {
+    let mut crossings = 0;
+    for (i, &(x1, y1)) in input.iter().enumerate() {
+        let (x2, y2) = input[(i + 1) % input.len()];
+        let (xs, ys) = (x1.min(x2)..=x1.max(x2), y1.min(y2)..=y1.max(y2));
+        if xs.contains(&x) && ys.contains(&y) {
+            return true;
+        }
+        if x1 == x2 && x1 > x && (y1.min(y2)..y1.max(y2)).contains(&y) {
+            crossings += 1;
+        }
+    }
+    crossings % 2 == 1
+}
+
+fn rectangles(input: &[Pos]) -> impl Iterator<Item = (Pos, Pos)> + '_ {
+    (0..input.len()).flat_map(move |i| (i + 1..input.len()).map(move |j| (input[i], input[j])))
+}
+
+fn area(((x1, y1), (x2, y2)): (Pos, Pos)) -> usize {
+    (x1.abs_diff(x2) + 1) * (y1.abs_diff(y2) + 1)
+}
+
+pub fn task1(input: &[Pos]) -> usize {
+    rectangles(input).map(area).max().unwrap()
+}
+
+/// Checks every tile of every rectangle.
+pub fn task2(input: &[Pos]) -> usize {
+    rectangles(input)
+        .filter(|&((x1, y1), (x2, y2))| {
+            (y1.min(y2)..=y1.max(y2))
+                .all(|y| (x1.min(x2)..=x1.max(x2)).all(|x| inside(input, (x, y))))
+        })
+        .map(area)
+        .max()
+        .unwrap()
+}
+
+/// Replaces each coordinate with twice its rank, keeping the loop's shape and a tile between
+/// neighbouring lines, so every tile can be checked.
+fn compress(input: &[Pos]) -> Vec<Pos> {
+    let xs: BTreeSet<_> = input.iter().map(|&(x, _)| x).collect();
+    let ys: BTreeSet<_> = input.iter().map(|&(_, y)| y).collect();
+    let rank = |set: &BTreeSet<usize>, v| 2 * set.range(..v).count();
+    input
+        .iter()
+        .map(|&(x, y)| (rank(&xs, x), rank(&ys, y)))
+        .collect()
+}
+
+fn render(input: &[Pos]) -> String {
+    let lines: Vec<_> = input.iter().map(|(x, y)| format!("{x},{y}")).collect();
+    lines.join("\n")
+}
+
+/// Compares both parts with tile-by-tile checks on compressed loops of up to 12 corners.
+///
+/// Loops aren't shrunk, as dropping corners rarely leaves a simple rectilinear loop.
+pub fn check(seeds: Range<u64>) -> Option<(u8, Mismatch<String, usize>)> {
+    let generate = |seed| {
+        let input = parse(&generator::generate(seed, 4 + seed as usize % 12)).unwrap();
+        compress(&input)
+    };
+    let parts: [(Solver, Solver); 2] = [
+        (|input| crate::task1(input), |input| task1(input)),
+        (|input| crate::task2(input), |input| task2(input)),
+    ];
+    (1..).zip(parts).find_map(|(part, (solve, reference))| {
+        let m = differential(seeds.clone(), generate, |_| Vec::new(), solve, reference)?;
+        Some((part, m.map_input(|i| render(i))))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compress() -> anyhow::Result<()> {
+        let input = parse("7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3")?;
+        assert_eq!(
+            render(&compress(&input)),
+            "2,0\n6,0\n6,6\n4,6\n4,4\n0,4\n0,2\n2,2"
+        );
+        assert_eq!((task1(&input), task2(&input)), (50, 24));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference() {
+        if let Some((part, m)) = check(0..500) {
+            panic!("part {part}: {m}");
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use anyhow::{Context, Result};
+use aoc::DayResult;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, write};
+use std::path::Path;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Expected {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+}
+
+/// The answers file: `[dN.<input hash>]` tables with the expected answer of each part.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, Expected>>);
+
+impl Answers {
+    /// A missing file is an empty registry, so `record` can create it.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let s = read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        toml::from_str(&s).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        write(path, toml::to_string(self)?).with_context(|| format!("writing {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, input: &str) -> Option<&Expected> {
+        self.0.get(&format!("d{day}"))?.get(&input_hash(input))
+    }
+
+    pub fn record(&mut self, input: &str, result: &DayResult) {
+        let expected = self
+            .0
+            .entry(format!("d{}", result.day))
+            .or_default()
+            .entry(input_hash(input))
+            .or_default();
+        for p in &result.parts {
+            match p.part {
+                1 => expected.part1 = Some(p.answer.clone()),
+                _ => expected.part2 = Some(p.answer.clone()),
+            }
+        }
+    }
+}
+
+/// 64-bit FNV-1a. `DefaultHasher` isn't stable across Rust releases, and the keys are committed.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::PartResult;
+    use std::time::Duration;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let part = |part, answer: &str| PartResult {
+            part,
+            answer: answer.to_string(),
+            time: Duration::ZERO,
+        };
+        let result = DayResult {
+            day: 12,
+            parse_time: Duration::ZERO,
+            parts: vec![part(1, "2")],
+        };
+        let mut answers = Answers::default();
+        answers.record("input", &result);
+
+        let answers: Answers = toml::from_str(&toml::to_string(&answers)?)?;
+        let expected = answers.get(12, "input").unwrap();
+        assert_eq!((expected.part(1), expected.part(2)), (Some("2"), None));
+        assert_eq!(answers.get(12, "other input"), None);
+        Ok(())
+    }
+}
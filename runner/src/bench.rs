@@ -0,0 +1,213 @@
+use anyhow::{Context, Result};
+use aoc::DayResult;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::{OpenOptions, read_to_string};
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Timing summary of repeated runs of one stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Uses nearest-rank percentiles, so every value is one of the samples.
+    pub fn new(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+        let rank = |p: f64| samples[((p * samples.len() as f64).ceil() as usize).max(1) - 1];
+        Self {
+            min: samples[0],
+            median: rank(0.5),
+            p95: rank(0.95),
+        }
+    }
+}
+
+/// `parse`, `part1` or `part2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(p) => write!(f, "part{p}"),
+        }
+    }
+}
+
+/// Collects the per-stage timings of repeated runs of the same day.
+pub fn stats(results: &[DayResult]) -> Vec<(Stage, Stats)> {
+    let mut stages = vec![(
+        Stage::Parse,
+        results.iter().map(|r| r.parse_time).collect::<Vec<_>>(),
+    )];
+    for r in results {
+        for p in &r.parts {
+            match stages.iter_mut().find(|(s, _)| *s == Stage::Part(p.part)) {
+                Some((_, samples)) => samples.push(p.time),
+                None => stages.push((Stage::Part(p.part), vec![p.time])),
+            }
+        }
+    }
+    stages
+        .into_iter()
+        .map(|(stage, mut samples)| (stage, Stats::new(&mut samples)))
+        .collect()
+}
+
+/// One line of the history file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub day: u8,
+    /// Hash of the input, as in the answers file, so different inputs aren't compared.
+    pub input: String,
+    pub stage: String,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Record {
+    pub fn new(day: u8, input: String, stage: Stage, runs: usize, stats: Stats) -> Self {
+        let ns = |d: Duration| d.as_nanos().try_into().unwrap_or(u64::MAX);
+        Self {
+            time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            day,
+            input,
+            stage: stage.to_string(),
+            runs,
+            min_ns: ns(stats.min),
+            median_ns: ns(stats.median),
+            p95_ns: ns(stats.p95),
+        }
+    }
+
+    /// The relative change of the median against `baseline`, e.g. `0.1` for 10% slower.
+    pub fn change(&self, baseline: &Record) -> f64 {
+        self.median_ns as f64 / baseline.median_ns.max(1) as f64 - 1.0
+    }
+}
+
+/// The bench history, a JSON Lines file that every bench run appends to.
+#[derive(Debug, Default)]
+pub struct History(Vec<Record>);
+
+impl History {
+    /// A missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let s = read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        s.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line)
+                    .with_context(|| format!("parsing {} line {}", path.display(), i + 1))
+            })
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+
+    /// The latest record for the same day, input and stage.
+    pub fn baseline(&self, record: &Record) -> Option<&Record> {
+        self.0
+            .iter()
+            .rev()
+            .find(|r| (r.day, &r.input, &r.stage) == (record.day, &record.input, &record.stage))
+    }
+
+    pub fn append(path: &Path, records: &[Record]) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("opening {}", path.display()))?;
+        for r in records {
+            writeln!(file, "{}", serde_json::to_string(r)?)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::PartResult;
+
+    fn ms(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&mut ms(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+
+        let mut samples = ms(&(1..=100).collect::<Vec<_>>());
+        samples.reverse();
+        let stats = Stats::new(&mut samples);
+        assert_eq!(stats.median, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+        assert_eq!(Stats::new(&mut ms(&[7])).p95, Duration::from_millis(7));
+    }
+
+    #[test]
+    fn test_stage_stats() {
+        let result = |parse, part1| DayResult {
+            day: 1,
+            parse_time: Duration::from_millis(parse),
+            parts: vec![PartResult {
+                part: 1,
+                answer: String::new(),
+                time: Duration::from_millis(part1),
+            }],
+        };
+        let stats = stats(&[result(1, 10), result(3, 30), result(2, 20)]);
+        let stages: Vec<_> = stats.iter().map(|&(s, st)| (s, st.median)).collect();
+        assert_eq!(
+            stages,
+            [
+                (Stage::Parse, Duration::from_millis(2)),
+                (Stage::Part(1), Duration::from_millis(20))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_history() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.jsonl", std::process::id()));
+        let stats = |ms| Stats::new(&mut [Duration::from_millis(ms)]);
+        let record = |day, ms| Record::new(day, "hash".to_string(), Stage::Part(1), 1, stats(ms));
+
+        History::append(&path, &[record(1, 10), record(2, 50)])?;
+        History::append(&path, &[record(1, 20)])?;
+        let history = History::load(&path);
+        std::fs::remove_file(&path)?;
+        let history = history?;
+
+        let current = record(1, 22);
+        let baseline = history.baseline(&current).unwrap();
+        assert_eq!(baseline.median_ns, 20_000_000);
+        assert!((current.change(baseline) - 0.1).abs() < 1e-9);
+        assert!(history.baseline(&record(3, 1)).is_none());
+        Ok(())
+    }
+}
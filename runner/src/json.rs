@@ -0,0 +1,65 @@
+use aoc::DayResult;
+use serde::Serialize;
+
+/// One line of `run --json` output. Times are in nanoseconds.
+#[derive(Debug, Serialize)]
+pub struct DayReport<'a> {
+    pub day: u8,
+    pub parse_time_ns: u128,
+    pub parts: Vec<PartReport<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartReport<'a> {
+    pub part: u8,
+    pub answer: &'a str,
+    pub solve_time_ns: u128,
+}
+
+impl<'a> From<&'a DayResult> for DayReport<'a> {
+    fn from(r: &'a DayResult) -> Self {
+        Self {
+            day: r.day,
+            parse_time_ns: r.parse_time.as_nanos(),
+            parts: r
+                .parts
+                .iter()
+                .map(|p| PartReport {
+                    part: p.part,
+                    answer: &p.answer,
+                    solve_time_ns: p.time.as_nanos(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Serializes a day's result as a single line of JSON.
+pub fn to_line(result: &DayResult) -> serde_json::Result<String> {
+    serde_json::to_string(&DayReport::from(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::PartResult;
+    use std::time::Duration;
+
+    #[test]
+    fn test_to_line() -> serde_json::Result<()> {
+        let result = DayResult {
+            day: 12,
+            parse_time: Duration::from_micros(3),
+            parts: vec![PartResult {
+                part: 1,
+                answer: "443".to_string(),
+                time: Duration::from_nanos(1500),
+            }],
+        };
+        assert_eq!(
+            to_line(&result)?,
+            r#"{"day":12,"parse_time_ns":3000,"parts":[{"part":1,"answer":"443","solve_time_ns":1500}]}"#
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,530 @@
+use answers::{Answers, input_hash};
+use anyhow::{Context, Result, bail};
+use aoc::check::Mismatch;
+use aoc::{DayResult, solve};
+use bench::{History, Record};
+use clap::{Args, Parser, Subcommand};
+use std::fmt::Display;
+use std::fs::read_to_string;
+use std::io::{Read, stdin, stdout};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+mod answers;
+mod bench;
+mod json;
+mod trace;
+
+type Solver = fn(&str, Option<u8>) -> Result<DayResult>;
+
+const DAYS: [Solver; 12] = [
+    solve::<d1::Day1>,
+    solve::<d2::Day2>,
+    solve::<d3::Day3>,
+    solve::<d4::Day4>,
+    solve::<d5::Day5>,
+    solve::<d6::Day6>,
+    solve::<d7::Day7>,
+    solve::<d8::Day8>,
+    solve::<d9::Day9>,
+    solve::<d10::Day10>,
+    solve::<d11::Day11>,
+    solve::<d12::Day12>,
+];
+
+type Generator = fn(u64, usize) -> String;
+
+const GENERATORS: [Option<Generator>; 12] = [
+    Some(d1::generator::generate),
+    Some(d2::generator::generate),
+    Some(d3::generator::generate),
+    Some(d4::generator::generate),
+    Some(d5::generator::generate),
+    Some(d6::generator::generate),
+    Some(d7::generator::generate),
+    Some(d8::generator::generate),
+    Some(d9::generator::generate),
+    Some(d10::generator::generate),
+    Some(d11::generator::generate),
+    Some(d12::generator::generate),
+];
+
+/// Runs a day's differential check over a range of seeds, describing the first mismatch.
+type Checker = fn(Range<u64>) -> Option<String>;
+
+fn report<O: Display>(mismatch: Option<(u8, Mismatch<String, O>)>) -> Option<String> {
+    mismatch.map(|(part, m)| format!("part {part}: {m}"))
+}
+
+const CHECKS: [Option<Checker>; 12] = [
+    Some(|seeds| report(d1::reference::check(seeds))),
+    Some(|seeds| report(d2::reference::check(seeds))),
+    Some(|seeds| report(d3::reference::check(seeds))),
+    Some(|seeds| report(d4::reference::check(seeds))),
+    Some(|seeds| report(d5::reference::check(seeds))),
+    Some(|seeds| report(d6::reference::check(seeds))),
+    Some(|seeds| report(d7::reference::check(seeds))),
+    Some(|seeds| report(d8::reference::check(seeds))),
+    Some(|seeds| report(d9::reference::check(seeds))),
+    Some(|seeds| report(d10::reference::check(seeds))),
+    Some(|seeds| report(d11::reference::check(seeds))),
+    Some(|seeds| report(d12::reference::check(seeds))),
+];
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Advent of Code 2025 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one day, or every day in sequence when `--day` is omitted
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+        /// Print one JSON object per day instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Compare answers against the answers file and report every mismatch
+    Verify {
+        #[command(flatten)]
+        selection: Selection,
+        #[arg(long, default_value_os_t = default_answers())]
+        answers: PathBuf,
+    },
+    /// Store the current answers in the answers file
+    Record {
+        #[command(flatten)]
+        selection: Selection,
+        #[arg(long, default_value_os_t = default_answers())]
+        answers: PathBuf,
+    },
+    /// Time parsing and each part over repeated runs and compare with the previous bench
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+        /// How many times to solve each day
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// History file that every bench appends to
+        #[arg(long, default_value_os_t = default_history())]
+        history: PathBuf,
+        /// Slowdown of the median against the previous bench, in percent, to flag
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Print a random input for a day, the same one for the same seed and size
+    Generate {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=12))]
+        day: u8,
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// Roughly how many lines, ranges or grid rows to generate
+        #[arg(short = 'n', long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Compare a day's solvers with its naive reference on generated inputs
+    Check {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=12))]
+        day: u8,
+        /// How many seeds to try, starting from 0
+        #[arg(short = 'n', long, default_value_t = 10_000)]
+        cases: u64,
+    },
+    /// Print what each of day 1's rotations does to the dial
+    Trace {
+        /// Input file, or `-` for stdin [default: d1/input.txt in the workspace]
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[arg(short, long, value_enum, default_value_t = trace::Format::Csv)]
+        format: trace::Format,
+        /// Number of positions on the dial
+        #[arg(long, default_value_t = 100)]
+        positions: u32,
+        #[arg(long, default_value_t = 50)]
+        start: u32,
+        /// Position whose passes and landings are counted; repeat for several
+        #[arg(long = "target", default_values_t = [0])]
+        targets: Vec<u32>,
+    },
+    /// Print the shortest day 1 input with the given answers
+    Synthesize {
+        #[arg(long)]
+        part1: u64,
+        #[arg(long)]
+        part2: u64,
+        /// Most rotations to allow
+        #[arg(long, default_value_t = 10_000)]
+        max_len: usize,
+    },
+}
+
+#[derive(Debug, Args)]
+struct Selection {
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=12))]
+    day: Option<u8>,
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Input file, or `-` for stdin [default: dN/input.txt in the workspace]
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+}
+
+impl Selection {
+    fn days(&self) -> impl Iterator<Item = (u8, PathBuf)> + '_ {
+        let days = self.day.map_or(1..=12, |day| day..=day);
+        days.map(|day| {
+            (
+                day,
+                self.input.clone().unwrap_or_else(|| default_input(day)),
+            )
+        })
+    }
+}
+
+fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn default_input(day: u8) -> PathBuf {
+    workspace().join(format!("d{day}")).join("input.txt")
+}
+
+fn default_answers() -> PathBuf {
+    workspace().join("answers.toml")
+}
+
+fn default_history() -> PathBuf {
+    workspace().join("bench-history.jsonl")
+}
+
+fn read_input(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut s = String::new();
+        stdin().read_to_string(&mut s)?;
+        return Ok(s);
+    }
+    read_to_string(path).with_context(|| format!("reading {}", path.display()))
+}
+
+fn solve_day(day: u8, part: Option<u8>, input: &str) -> Result<DayResult> {
+    DAYS[day as usize - 1](input, part).with_context(|| format!("day {day}"))
+}
+
+fn run_day(day: u8, part: Option<u8>, input: &Path) -> Result<DayResult> {
+    solve_day(day, part, &read_input(input)?)
+}
+
+/// Prints one line per checked part and returns how many of them didn't match.
+fn verify(selection: &Selection, answers: &Answers) -> Result<usize> {
+    let mut mismatches = 0;
+    for (day, input) in selection.days() {
+        let input = read_input(&input)?;
+        let Some(expected) = answers.get(day, &input) else {
+            println!("day {day}: no recorded answers for this input");
+            continue;
+        };
+        for p in solve_day(day, selection.part, &input)?.parts {
+            match expected.part(p.part) {
+                Some(e) if e == p.answer => println!("day {day} part {}: ok", p.part),
+                Some(e) => {
+                    mismatches += 1;
+                    println!("day {day} part {}: expected {e}, got {}", p.part, p.answer);
+                }
+                None => println!("day {day} part {}: no recorded answer", p.part),
+            }
+        }
+    }
+    Ok(mismatches)
+}
+
+/// Prints the timings of every stage and returns how many of them regressed by more than
+/// `threshold` percent. The new timings are appended to the history either way.
+fn bench(selection: &Selection, runs: usize, history: &Path, threshold: f64) -> Result<usize> {
+    let baselines = History::load(history)?;
+    let mut records = Vec::new();
+    let mut regressions = 0;
+
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  Change",
+        "Day", "Stage", "Min", "Median", "P95"
+    );
+    for (day, input) in selection.days() {
+        let input = read_input(&input)?;
+        let results = (0..runs)
+            .map(|_| solve_day(day, selection.part, &input))
+            .collect::<Result<Vec<_>>>()?;
+        for (stage, stats) in bench::stats(&results) {
+            let record = Record::new(day, input_hash(&input), stage, runs, stats);
+            let change = match baselines.baseline(&record).map(|b| record.change(b)) {
+                Some(c) if c * 100.0 > threshold => {
+                    regressions += 1;
+                    format!("{:+.1}% regression", c * 100.0)
+                }
+                Some(c) => format!("{:+.1}%", c * 100.0),
+                None => "-".to_string(),
+            };
+            println!(
+                "{day:>3}  {:<6}  {:>10.2?}  {:>10.2?}  {:>10.2?}  {change}",
+                stage.to_string(),
+                stats.min,
+                stats.median,
+                stats.p95
+            );
+            records.push(record);
+        }
+    }
+    History::append(history, &records)?;
+    Ok(regressions)
+}
+
+fn print_summary(results: &[DayResult]) {
+    let answer = |r: &DayResult, part| {
+        r.parts
+            .iter()
+            .find(|p| p.part == part)
+            .map_or_else(|| "-".to_string(), |p| p.answer.clone())
+    };
+    let rows: Vec<_> = results
+        .iter()
+        .map(|r| {
+            let total = r.parse_time + r.parts.iter().map(|p| p.time).sum::<Duration>();
+            [
+                r.day.to_string(),
+                answer(r, 1),
+                answer(r, 2),
+                format!("{total:.2?}"),
+            ]
+        })
+        .collect();
+
+    let header = ["Day", "Part 1", "Part 2", "Time"];
+    let widths: Vec<_> = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .map(|r| r[i].len())
+                .fold(header[i].len(), usize::max)
+        })
+        .collect();
+    let print_row = |row: [&str; 4]| {
+        println!(
+            "{:>w0$}  {:<w1$}  {:<w2$}  {:>w3$}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+    };
+    print_row(header);
+    for row in &rows {
+        print_row([&row[0], &row[1], &row[2], &row[3]]);
+    }
+}
+
+fn run(selection: &Selection, json: bool) -> Result<()> {
+    let results = selection
+        .days()
+        .map(|(day, input)| run_day(day, selection.part, &input))
+        .collect::<Result<Vec<_>>>()?;
+
+    match (selection.day, results.as_slice()) {
+        (Some(day), [result]) if result.parts.is_empty() => {
+            bail!(
+                "day {day} has no part {}",
+                selection.part.unwrap_or_default()
+            );
+        }
+        _ if json => {
+            for result in &results {
+                println!("{}", json::to_line(result)?);
+            }
+        }
+        (Some(_), [result]) => {
+            for p in &result.parts {
+                println!("Task {}: {}", p.part, p.answer);
+            }
+        }
+        _ => print_summary(&results),
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Run { selection, json } => run(&selection, json),
+        Command::Verify { selection, answers } => {
+            let mismatches = verify(&selection, &Answers::load(&answers)?)?;
+            if mismatches > 0 {
+                bail!("{mismatches} answer(s) don't match {}", answers.display());
+            }
+            Ok(())
+        }
+        Command::Record {
+            selection,
+            answers: path,
+        } => {
+            let mut answers = Answers::load(&path)?;
+            for (day, input) in selection.days() {
+                let input = read_input(&input)?;
+                answers.record(&input, &solve_day(day, selection.part, &input)?);
+            }
+            answers.save(&path)
+        }
+        Command::Bench {
+            selection,
+            runs,
+            history,
+            threshold,
+        } => {
+            let regressions = bench(&selection, runs as usize, &history, threshold)?;
+            if regressions > 0 {
+                bail!("{regressions} stage(s) regressed by more than {threshold}%");
+            }
+            Ok(())
+        }
+        Command::Generate { day, seed, size } => {
+            let Some(generate) = GENERATORS[day as usize - 1] else {
+                bail!("day {day} has no generator");
+            };
+            // No trailing newline, which most days' parsers reject.
+            print!("{}", generate(seed, size));
+            Ok(())
+        }
+        Command::Check { day, cases } => {
+            let Some(check) = CHECKS[day as usize - 1] else {
+                bail!("day {day} has no reference implementation");
+            };
+            if let Some(mismatch) = check(0..cases) {
+                bail!("day {day} {mismatch}");
+            }
+            println!("day {day}: {cases} cases agree");
+            Ok(())
+        }
+        Command::Trace {
+            input,
+            format,
+            positions,
+            start,
+            targets,
+        } => {
+            let input = read_input(&input.unwrap_or_else(|| default_input(1)))?;
+            let rots = d1::parse(&input)?;
+            let dial = d1::Dial::new(positions, start, targets)?;
+            trace::write(&mut stdout().lock(), dial.trace(&rots), format)?;
+            Ok(())
+        }
+        Command::Synthesize {
+            part1,
+            part2,
+            max_len,
+        } => {
+            let Some(rots) = d1::synthesize(part1, part2, max_len) else {
+                bail!("no input of at most {max_len} rotations has answers {part1} and {part2}");
+            };
+            for rot in rots {
+                println!("{rot}");
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_days_in_order() -> Result<()> {
+        for day in [1, 5, 12] {
+            assert_eq!(run_day(day, Some(1), &default_input(day))?.day, day);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_part() -> Result<()> {
+        assert!(run_day(12, Some(2), &default_input(12))?.parts.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let selection = Selection {
+            day: None,
+            part: None,
+            input: None,
+        };
+        assert_eq!(verify(&selection, &Answers::load(&default_answers())?)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bench() -> Result<()> {
+        let selection = Selection {
+            day: Some(1),
+            part: None,
+            input: None,
+        };
+        let history =
+            std::env::temp_dir().join(format!("aoc-history-{}.jsonl", std::process::id()));
+        let first = bench(&selection, 3, &history, 10.0);
+        // Any change at all is a regression against a -100% threshold.
+        let second = bench(&selection, 3, &history, -100.0);
+        std::fs::remove_file(&history)?;
+        assert_eq!((first?, second?), (0, 3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_inputs() -> Result<()> {
+        for (day, generate) in (1..).zip(GENERATORS) {
+            if let Some(generate) = generate {
+                assert_eq!(solve_day(day, None, &generate(0, 20))?.day, day);
+                // The same seed must give the same input, and another seed another one.
+                assert_eq!(generate(1, 20), generate(1, 20), "day {day}");
+                assert_ne!(generate(1, 20), generate(2, 20), "day {day}");
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_checks() {
+        for (day, check) in (1..).zip(CHECKS) {
+            if let Some(mismatch) = check.and_then(|check| check(0..100)) {
+                panic!("day {day} {mismatch}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_mismatch() -> Result<()> {
+        let selection = Selection {
+            day: Some(1),
+            part: None,
+            input: None,
+        };
+        let input = read_input(&default_input(1))?;
+        let mut result = solve_day(1, None, &input)?;
+        result.parts[1].answer.push('0');
+
+        let mut answers = Answers::default();
+        answers.record(&input, &result);
+        assert_eq!(verify(&selection, &answers)?, 1);
+        Ok(())
+    }
+}
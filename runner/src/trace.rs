@@ -0,0 +1,85 @@
+use clap::ValueEnum;
+use d1::Step;
+use serde::Serialize;
+use std::io::{self, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Csv,
+    /// JSON Lines, one object per rotation
+    Jsonl,
+}
+
+#[derive(Debug, Serialize)]
+struct Row {
+    rot: String,
+    start: u32,
+    end: u32,
+    passes: u64,
+    landed: bool,
+}
+
+/// Writes day 1's per-rotation trace, with a header line for CSV.
+pub fn write(
+    out: &mut impl Write,
+    steps: impl Iterator<Item = Step>,
+    format: Format,
+) -> io::Result<()> {
+    if format == Format::Csv {
+        writeln!(out, "rot,start,end,passes,landed")?;
+    }
+    for s in steps {
+        match format {
+            Format::Csv => writeln!(
+                out,
+                "{},{},{},{},{}",
+                s.rot, s.start, s.end, s.passes, s.landed
+            )?,
+            Format::Jsonl => {
+                let row = Row {
+                    rot: s.rot.to_string(),
+                    start: s.start,
+                    end: s.end,
+                    passes: s.passes,
+                    landed: s.landed,
+                };
+                writeln!(out, "{}", serde_json::to_string(&row)?)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use d1::Dial;
+
+    fn trace(format: Format) -> Result<String> {
+        let input = d1::parse("L68\nL30\nR48")?;
+        let mut out = Vec::new();
+        write(&mut out, Dial::default().trace(&input), format)?;
+        Ok(String::from_utf8(out)?)
+    }
+
+    #[test]
+    fn test_csv() -> Result<()> {
+        let csv = trace(Format::Csv)?;
+        let expected =
+            "rot,start,end,passes,landed\nL68,50,82,1,false\nL30,82,52,0,false\nR48,52,0,1,true\n";
+        assert_eq!(csv, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_jsonl() -> Result<()> {
+        let jsonl = trace(Format::Jsonl)?;
+        assert_eq!(jsonl.lines().count(), 3);
+        assert_eq!(
+            jsonl.lines().last(),
+            Some(r#"{"rot":"R48","start":52,"end":0,"passes":1,"landed":true}"#)
+        );
+        Ok(())
+    }
+}